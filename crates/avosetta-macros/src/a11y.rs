@@ -0,0 +1,402 @@
+use std::collections::HashSet;
+
+use proc_macro2::Span;
+use syn::{Expr, ExprLit, Lit, spanned::Spanned};
+
+use crate::ast::{
+    Attr, AttrItem, Attrs, Element, Group, InterpArmExpr, InterpValue, Lint, Name, Node,
+};
+
+/// The `aria-*` attributes defined by WAI-ARIA.
+const ARIA_ATTRS: &[&str] = &[
+    "aria-activedescendant",
+    "aria-atomic",
+    "aria-autocomplete",
+    "aria-braillelabel",
+    "aria-brailleroledescription",
+    "aria-busy",
+    "aria-checked",
+    "aria-colcount",
+    "aria-colindex",
+    "aria-colindextext",
+    "aria-colspan",
+    "aria-controls",
+    "aria-current",
+    "aria-describedby",
+    "aria-description",
+    "aria-details",
+    "aria-disabled",
+    "aria-dropeffect",
+    "aria-errormessage",
+    "aria-expanded",
+    "aria-flowto",
+    "aria-grabbed",
+    "aria-haspopup",
+    "aria-hidden",
+    "aria-invalid",
+    "aria-keyshortcuts",
+    "aria-label",
+    "aria-labelledby",
+    "aria-level",
+    "aria-live",
+    "aria-modal",
+    "aria-multiline",
+    "aria-multiselectable",
+    "aria-orientation",
+    "aria-owns",
+    "aria-placeholder",
+    "aria-posinset",
+    "aria-pressed",
+    "aria-readonly",
+    "aria-relevant",
+    "aria-required",
+    "aria-roledescription",
+    "aria-rowcount",
+    "aria-rowindex",
+    "aria-rowindextext",
+    "aria-rowspan",
+    "aria-selected",
+    "aria-setsize",
+    "aria-sort",
+    "aria-valuemax",
+    "aria-valuemin",
+    "aria-valuenow",
+    "aria-valuetext",
+];
+
+/// Attributes that give an element an accessible name without text content.
+const NAME_ATTRS: &[&str] = &["aria-label", "aria-labelledby", "title"];
+
+/// `input` types that are labelled by their value or not shown at all.
+const UNLABELLED_INPUTS: &[&str] = &["button", "hidden", "image", "reset", "submit"];
+
+/// Checks `group` for common accessibility mistakes, reporting each one as a
+/// warning, or as an error if `lint` is [`Lint::Deny`].
+///
+/// Only what is written in the template is checked. An expression may render
+/// text or a label, so it is assumed to, and the ids in different branches of
+/// an `@if` or `@match` are not compared with each other.
+pub fn check(group: &Group, lint: Lint) -> Option<syn::Error> {
+    let mut checker = Checker {
+        lint,
+        ids: HashSet::new(),
+        labels: Vec::new(),
+        dynamic_labels: false,
+        inputs: Vec::new(),
+        in_label: 0,
+        in_foreign: 0,
+        errors: None,
+    };
+
+    checker.group(group);
+
+    if !checker.dynamic_labels {
+        for (id, span) in std::mem::take(&mut checker.inputs) {
+            if id.is_none_or(|x| !checker.labels.contains(&x)) {
+                checker.report(
+                    span,
+                    "`input` has no label; put it inside a `label`, point a `label[for=...]` at \
+                     its `id`, or add an `aria-label`"
+                        .to_owned(),
+                );
+            }
+        }
+    }
+
+    checker.errors
+}
+
+struct Checker {
+    lint: Lint,
+    ids: HashSet<String>,
+    labels: Vec<String>,
+    dynamic_labels: bool,
+    inputs: Vec<(Option<String>, Span)>,
+    in_label: usize,
+    in_foreign: usize,
+    errors: Option<syn::Error>,
+}
+
+impl Checker {
+    fn group(&mut self, group: &Group) {
+        for node in &group.0 {
+            match node {
+                Node::Element(x) => self.element(x),
+                Node::Interp(x) => self.interp(&x.value),
+                Node::Literal(_) => {}
+            }
+        }
+    }
+
+    /// Checks groups of which at most one is rendered.
+    fn branches<'a, I>(&mut self, groups: I)
+    where
+        I: IntoIterator<Item = &'a Group>,
+    {
+        let before = self.ids.clone();
+        let mut after = before.clone();
+
+        for group in groups {
+            self.ids = before.clone();
+            self.group(group);
+            after.extend(self.ids.drain());
+        }
+
+        self.ids = after;
+    }
+
+    fn element(&mut self, element: &Element) {
+        let (name, attrs, inner) = match element {
+            Element::Normal(x) => (&x.name, &x.attrs, Some(&x.inner)),
+            Element::Void(x) => (&x.name, &x.attrs, None),
+
+            Element::Component(x) => {
+                if let Some(children) = &x.children {
+                    for slot in &children.slots {
+                        self.group(&slot.group);
+                    }
+
+                    self.group(&children.group);
+                }
+
+                return;
+            }
+        };
+
+        let value = name.value().to_ascii_lowercase();
+
+        self.attrs(attrs);
+
+        // A spread may add any attribute, so only its other attributes are
+        // checked.
+        let spread = attrs.inner.iter().any(|x| matches!(x, AttrItem::Spread(_)));
+
+        if self.in_foreign == 0 && !spread {
+            self.check(&value, name, attrs, inner);
+        } else if value == "label" {
+            self.dynamic_labels = true;
+        }
+
+        let Some(inner) = inner else {
+            return;
+        };
+
+        let foreign = matches!(value.as_str(), "svg" | "math");
+        let label = value == "label";
+
+        self.in_foreign += usize::from(foreign);
+        self.in_label += usize::from(label);
+        self.group(inner);
+        self.in_foreign -= usize::from(foreign);
+        self.in_label -= usize::from(label);
+    }
+
+    fn interp(&mut self, value: &InterpValue) {
+        match value {
+            InterpValue::Match(x) => self.branches(x.arms.iter().filter_map(|x| match &x.expr {
+                InterpArmExpr::Group(x) => Some(&x.group),
+                InterpArmExpr::Literal(_) => None,
+            })),
+
+            InterpValue::If(x) => self.branches(
+                std::iter::once(&x.then_branch)
+                    .chain(x.else_if.iter().map(|x| &x.group))
+                    .chain(x.else_branch.iter().map(|x| &x.group)),
+            ),
+
+            InterpValue::For(x) => self.group(&x.body),
+
+            InterpValue::Defer(x) => {
+                self.branches(std::iter::once(&x.body).chain(x.fallback.iter().map(|x| &x.group)))
+            }
+
+            InterpValue::Block(x) => self.group(&x.group),
+
+            InterpValue::Await(_)
+            | InterpValue::Extends(_)
+            | InterpValue::Expr(_)
+            | InterpValue::Stmt(_) => {}
+        }
+    }
+
+    fn attrs(&mut self, attrs: &Attrs) {
+        for attr in attrs.named() {
+            let name = attr.name.value().to_ascii_lowercase();
+
+            if name == "id"
+                && let Some(id) = literal(attr)
+            {
+                let span = attr
+                    .value
+                    .as_ref()
+                    .map_or(attr.name.span(), |x| x.expr.span());
+
+                if !self.ids.insert(id.clone()) {
+                    self.report(span, format!("the `id` \"{id}\" is used more than once"));
+                }
+            }
+
+            if name.starts_with("aria-") && !ARIA_ATTRS.contains(&name.as_str()) {
+                let message = match crate::names::suggest(&name, ARIA_ATTRS.iter().copied()) {
+                    Some(x) => format!("unknown ARIA attribute `{name}`; did you mean `{x}`?"),
+                    None => format!("unknown ARIA attribute `{name}`"),
+                };
+
+                self.report(attr.name.span(), message);
+            }
+        }
+    }
+
+    fn check(&mut self, value: &str, name: &Name, attrs: &Attrs, inner: Option<&Group>) {
+        match value {
+            "img" if find(attrs, "alt").is_none() && !has_name(attrs) => {
+                self.report(
+                    name.span(),
+                    "`img` has no `alt` text; use `alt=\"\"` if it is decorative".to_owned(),
+                );
+            }
+
+            "input" => {
+                let kind = find(attrs, "type").map(literal);
+
+                // An input whose type is not known may not need a label.
+                let Some(kind) = kind.unwrap_or(Some("text".to_owned())) else {
+                    return;
+                };
+
+                if UNLABELLED_INPUTS.contains(&kind.to_ascii_lowercase().as_str())
+                    || has_name(attrs)
+                    || self.in_label > 0
+                {
+                    return;
+                }
+
+                match find(attrs, "id") {
+                    Some(attr) => {
+                        if let Some(id) = literal(attr) {
+                            self.inputs.push((Some(id), name.span()));
+                        }
+                    }
+
+                    None => self.inputs.push((None, name.span())),
+                }
+            }
+
+            "label" => {
+                if let Some(attr) = find(attrs, "for") {
+                    match literal(attr) {
+                        Some(x) => self.labels.push(x),
+                        None => self.dynamic_labels = true,
+                    }
+                }
+            }
+
+            "a" | "button" if !has_name(attrs) && !inner.is_some_and(has_content) => {
+                let message = format!(
+                    "`{value}` has no text content; add text or an `aria-label` to describe it"
+                );
+
+                self.report(name.span(), message);
+            }
+
+            _ => {}
+        }
+    }
+
+    fn report(&mut self, span: Span, message: String) {
+        match self.lint {
+            Lint::Warn => crate::diagnostic::warn(span, message),
+
+            Lint::Deny => {
+                let err = syn::Error::new(span, message);
+
+                match &mut self.errors {
+                    Some(x) => x.combine(err),
+                    None => self.errors = Some(err),
+                }
+            }
+        }
+    }
+}
+
+/// Returns `true` if `group` may render text that names its parent.
+fn has_content(group: &Group) -> bool {
+    group.0.iter().any(|node| match node {
+        Node::Literal(x) => !x.value().trim().is_empty(),
+        Node::Interp(x) => interp_has_content(&x.value),
+
+        Node::Element(Element::Component(_)) => true,
+
+        Node::Element(Element::Normal(x)) => {
+            if is_hidden(&x.attrs) {
+                false
+            } else {
+                has_name(&x.attrs) || has_content(&x.inner)
+            }
+        }
+
+        Node::Element(Element::Void(x)) => {
+            if is_hidden(&x.attrs) {
+                return false;
+            }
+
+            let alt = find(&x.attrs, "alt").map(literal);
+            has_name(&x.attrs) || alt.is_some_and(|x| x.is_none_or(|x| !x.trim().is_empty()))
+        }
+    })
+}
+
+fn interp_has_content(value: &InterpValue) -> bool {
+    match value {
+        InterpValue::Match(x) => x.arms.iter().any(|x| match &x.expr {
+            InterpArmExpr::Group(x) => has_content(&x.group),
+            InterpArmExpr::Literal(x) => !x.value().trim().is_empty(),
+        }),
+
+        InterpValue::If(x) => {
+            has_content(&x.then_branch)
+                || x.else_if.iter().any(|x| has_content(&x.group))
+                || x.else_branch
+                    .as_ref()
+                    .is_some_and(|x| has_content(&x.group))
+        }
+
+        InterpValue::For(x) => has_content(&x.body),
+        InterpValue::Block(x) => has_content(&x.group),
+
+        InterpValue::Defer(x) => {
+            has_content(&x.body) || x.fallback.as_ref().is_some_and(|x| has_content(&x.group))
+        }
+
+        InterpValue::Stmt(_) => false,
+
+        InterpValue::Await(_) | InterpValue::Extends(_) | InterpValue::Expr(_) => true,
+    }
+}
+
+fn find<'a>(attrs: &'a Attrs, name: &str) -> Option<&'a Attr> {
+    attrs
+        .named()
+        .find(|x| x.name.value().eq_ignore_ascii_case(name))
+}
+
+fn has_name(attrs: &Attrs) -> bool {
+    NAME_ATTRS.iter().any(|x| find(attrs, x).is_some())
+}
+
+fn is_hidden(attrs: &Attrs) -> bool {
+    find(attrs, "aria-hidden")
+        .and_then(literal)
+        .is_some_and(|x| x == "true")
+}
+
+/// Returns the value of `attr` if it is a string literal.
+fn literal(attr: &Attr) -> Option<String> {
+    match &attr.value.as_ref()?.expr {
+        Expr::Lit(ExprLit {
+            lit: Lit::Str(x), ..
+        }) => Some(x.value()),
+
+        _ => None,
+    }
+}
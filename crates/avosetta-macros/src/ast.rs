@@ -1,11 +1,18 @@
 use proc_macro2::TokenStream;
 use quote::ToTokens;
 use syn::{
-    Expr, Ident, LitStr, Pat, Stmt, Token,
+    Expr, Ident, LitStr, Pat, Path, Stmt, Token,
     punctuated::Punctuated,
-    token::{Brace, Bracket},
+    token::{Brace, Bracket, Paren},
 };
 
+pub mod kw {
+    syn::custom_keyword!(block);
+    syn::custom_keyword!(defer);
+    syn::custom_keyword!(extends);
+    syn::custom_keyword!(slot);
+}
+
 pub struct Input {
     pub crate_ident: CrateIdent,
     pub _comma: Token![,],
@@ -15,6 +22,7 @@ pub struct Input {
 pub enum CrateIdent {
     Crate(Token![crate]),
     Ident(Ident),
+    Path(Path),
 }
 
 impl Clone for CrateIdent {
@@ -23,10 +31,35 @@ impl Clone for CrateIdent {
         match self {
             Self::Crate(x) => Self::Crate(Token![crate](x.span)),
             Self::Ident(x) => Self::Ident(x.clone()),
+            Self::Path(x) => Self::Path(x.clone()),
         }
     }
 }
 
+pub struct Template {
+    pub options: Options,
+    pub group: Group,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct Options {
+    pub reusable: bool,
+    pub send: bool,
+    pub nested: bool,
+    pub check_urls: bool,
+    pub check_names: bool,
+    pub check_nesting: bool,
+    pub check_a11y: Option<Lint>,
+    pub layout: Option<TokenStream>,
+    pub tokens: TokenStream,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Lint {
+    Warn,
+    Deny,
+}
+
 pub struct Group(pub Box<[Node]>);
 
 #[allow(clippy::large_enum_variant)]
@@ -39,19 +72,48 @@ pub enum Node {
 pub enum Element {
     Normal(Normal),
     Void(Void),
+    Component(Component),
 }
 
 pub struct Normal {
     pub name: Name,
     pub attrs: Attrs,
-    pub _brace: Brace,
+    pub brace: Brace,
     pub inner: Group,
 }
 
 pub struct Void {
     pub name: Name,
     pub attrs: Attrs,
-    pub _semi_token: Token![;],
+    pub semi_token: Token![;],
+}
+
+pub struct Component {
+    pub _at_token: Token![@],
+    pub path: Path,
+    pub _paren: Paren,
+    pub props: Punctuated<Prop, Token![,]>,
+    pub children: Option<Children>,
+}
+
+pub struct Prop {
+    pub name: Ident,
+    pub _eq_token: Token![=],
+    pub expr: Expr,
+}
+
+pub struct Children {
+    pub _brace: Brace,
+    pub slots: Box<[SlotFill]>,
+    pub group: Group,
+}
+
+pub struct SlotFill {
+    pub _at_token: Token![@],
+    pub _slot_token: kw::slot,
+    pub name: Ident,
+    pub _brace: Brace,
+    pub group: Group,
 }
 
 pub enum Name {
@@ -61,7 +123,40 @@ pub enum Name {
 
 pub struct Attrs {
     pub _bracket: Bracket,
-    pub inner: Punctuated<Attr, Token![,]>,
+    pub inner: Punctuated<AttrItem, Token![,]>,
+}
+
+pub enum AttrItem {
+    Attr(Attr),
+    Toggle(Toggle),
+    Style(StyleMap),
+    Spread(Spread),
+}
+
+pub struct Toggle {
+    pub class: Name,
+    pub _colon_token: Token![:],
+    pub name: Name,
+    pub _eq_token: Token![=],
+    pub cond: Expr,
+}
+
+pub struct StyleMap {
+    pub style: Name,
+    pub _colon_token: Token![:],
+    pub _brace: Brace,
+    pub decls: Punctuated<StyleDecl, Token![,]>,
+}
+
+pub struct StyleDecl {
+    pub property: Name,
+    pub _colon_token: Token![:],
+    pub value: Expr,
+}
+
+pub struct Spread {
+    pub _dot2_token: Token![..],
+    pub expr: Expr,
 }
 
 pub struct Attr {
@@ -83,10 +178,41 @@ pub enum InterpValue {
     Match(InterpMatch),
     If(InterpIf),
     For(InterpFor),
+    Await(InterpAwait),
+    Defer(InterpDefer),
+    Block(InterpBlock),
+    Extends(InterpExtends),
     Expr(Expr),
     Stmt(Stmt),
 }
 
+pub struct InterpAwait {
+    pub await_token: Token![await],
+    pub expr: Expr,
+}
+
+pub struct InterpDefer {
+    pub defer_token: kw::defer,
+    pub suspense: Expr,
+    pub _brace: Brace,
+    pub body: Group,
+    pub fallback: Option<InterpElse>,
+}
+
+pub struct InterpBlock {
+    pub block_token: kw::block,
+    pub _name: Ident,
+    pub _brace: Brace,
+    pub group: Group,
+}
+
+pub struct InterpExtends {
+    pub _extends_token: kw::extends,
+    pub path: Path,
+    pub _brace: Brace,
+    pub body: TokenStream,
+}
+
 pub struct InterpFor {
     pub _for_token: Token![for],
     pub pat: Pat,
@@ -149,6 +275,7 @@ impl ToTokens for CrateIdent {
         match self {
             CrateIdent::Crate(x) => x.to_tokens(tokens),
             CrateIdent::Ident(x) => x.to_tokens(tokens),
+            CrateIdent::Path(x) => x.to_tokens(tokens),
         }
     }
 }
@@ -0,0 +1,259 @@
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{
+    Attribute, Data, DeriveInput, Fields, GenericParam, Token, TypeParam, parse::Parse, parse_quote,
+};
+
+use crate::{
+    ast::{
+        CrateIdent, Group, Interp, InterpArm, InterpArmExpr, InterpArmGroup, InterpMatch,
+        InterpValue, Node, Template,
+    },
+    generate::{Generate, Stream},
+    template::Field,
+};
+
+enum HtmlAttr {
+    Crate(CrateIdent),
+    Template(Template),
+}
+
+impl Parse for HtmlAttr {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        if input.peek(Token![crate]) && input.peek2(Token![=]) {
+            let _crate: Token![crate] = input.parse()?;
+            let _eq: Token![=] = input.parse()?;
+            Ok(Self::Crate(input.parse()?))
+        } else {
+            Ok(Self::Template(input.parse()?))
+        }
+    }
+}
+
+/// Parses the `#[html(...)]` attributes, returning the `crate = path` argument
+/// along with the attribute it was written in, and the template.
+type HtmlAttrs<'a> = (Option<(CrateIdent, &'a Attribute)>, Option<Template>);
+
+fn parse_attrs(attrs: &[Attribute]) -> syn::Result<HtmlAttrs<'_>> {
+    let mut crate_ident = None;
+    let mut template = None;
+
+    for attr in attrs.iter().filter(|x| x.path().is_ident("html")) {
+        match attr.parse_args::<HtmlAttr>()? {
+            HtmlAttr::Crate(x) => crate_ident = Some((x, attr)),
+
+            HtmlAttr::Template(_) if template.is_some() => {
+                return Err(syn::Error::new_spanned(
+                    attr,
+                    "duplicate `#[html(...)]` template",
+                ));
+            }
+
+            HtmlAttr::Template(x) => template = Some(x),
+        }
+    }
+
+    Ok((crate_ident, template))
+}
+
+fn bindings(fields: &Fields) -> TokenStream {
+    match fields {
+        Fields::Named(x) => {
+            let idents = x.named.iter().map(|x| &x.ident);
+            quote! { { #(#idents),* } }
+        }
+
+        Fields::Unnamed(x) => {
+            let idents = (0..x.unnamed.len()).map(|i| format_ident!("_{}", i));
+            quote! { ( #(#idents),* ) }
+        }
+
+        Fields::Unit => TokenStream::new(),
+    }
+}
+
+pub fn expand(input: DeriveInput) -> syn::Result<TokenStream> {
+    crate::completion::clear();
+    crate::diagnostic::clear();
+
+    let (crate_ident, template) = parse_attrs(&input.attrs)?;
+    let crate_ident =
+        crate_ident.map_or_else(|| CrateIdent::Path(parse_quote!(::avosetta)), |(x, _)| x);
+
+    let ident = &input.ident;
+    let mut builder = TokenStream::new();
+    let buf = Ident::new("__B", Span::mixed_site());
+    let write_ident = Ident::new("__s", Span::mixed_site());
+
+    let body = match &input.data {
+        Data::Struct(data) => {
+            let Some(template) = template else {
+                return Err(syn::Error::new_spanned(
+                    ident,
+                    "expected a `#[html(...)]` attribute holding the template",
+                ));
+            };
+
+            if let Some(err) = crate::check(&template) {
+                return Err(err);
+            }
+
+            // Structs with named fields can be invoked as components.
+            if let Fields::Named(x) = &data.fields {
+                let fields = x
+                    .named
+                    .iter()
+                    .map(|x| Field {
+                        ident: x.ident.as_ref().unwrap(),
+                        vis: &x.vis,
+                        ty: x.ty.clone(),
+                        slot: false,
+                        optional: false,
+                    })
+                    .collect::<Vec<_>>();
+
+                builder = crate::template::builder(
+                    &crate_ident,
+                    &input.vis,
+                    ident,
+                    &input.generics,
+                    &fields,
+                );
+            }
+
+            let pat = bindings(&data.fields);
+
+            let mut stream = Stream::new(crate_ident.clone(), template.options);
+            template.group.generate(&mut stream);
+            let tokens = stream.into_token_stream();
+
+            quote! {
+                let Self #pat = self;
+                #crate_ident::Html::write(#tokens, #write_ident)
+            }
+        }
+
+        Data::Enum(data) => {
+            if template.is_some() {
+                return Err(syn::Error::new_spanned(
+                    ident,
+                    "`#[html(...)]` templates on enums belong on each variant",
+                ));
+            }
+
+            let mut arms = Vec::new();
+
+            for variant in &data.variants {
+                let (variant_crate, template) = parse_attrs(&variant.attrs)?;
+
+                if let Some((_, attr)) = variant_crate {
+                    return Err(syn::Error::new_spanned(
+                        attr,
+                        "`#[html(crate = ...)]` belongs on the enum, not on a variant",
+                    ));
+                }
+
+                let Some(template) = template else {
+                    return Err(syn::Error::new_spanned(
+                        &variant.ident,
+                        "expected a `#[html(...)]` attribute holding the template",
+                    ));
+                };
+
+                if let Some(err) = crate::check(&template) {
+                    return Err(err);
+                }
+
+                // Each variant is generated as its own template, so that its
+                // options apply to it alone, and is interpolated in its arm.
+                let mut stream = Stream::new(crate_ident.clone(), template.options);
+                template.group.generate(&mut stream);
+                let tokens = stream.into_token_stream();
+
+                let variant_ident = &variant.ident;
+                let pat = bindings(&variant.fields);
+
+                arms.push(InterpArm {
+                    pat: parse_quote!(Self::#variant_ident #pat),
+                    _fat_arrow_token: Default::default(),
+                    expr: InterpArmExpr::Group(InterpArmGroup {
+                        _brace: Default::default(),
+                        group: Group(Box::new([Node::Interp(Interp {
+                            _at_token: Default::default(),
+                            value: InterpValue::Expr(parse_quote!(#tokens)),
+                        })])),
+                    }),
+                    _comma_token: None,
+                });
+            }
+
+            // The match is generated the same way as `@match` in a template.
+            let interp = InterpMatch {
+                _match_token: Default::default(),
+                expr: parse_quote!(self),
+                _brace_token: Default::default(),
+                arms: arms.into_boxed_slice(),
+            };
+
+            let mut stream = Stream::new(crate_ident.clone(), Default::default());
+            interp.generate(&mut stream);
+            let tokens = stream.into_token_stream();
+
+            quote! {
+                #crate_ident::Html::write(#tokens, #write_ident)
+            }
+        }
+
+        Data::Union(data) => {
+            return Err(syn::Error::new_spanned(
+                data.union_token,
+                "`Html` cannot be derived for unions",
+            ));
+        }
+    };
+
+    let mut generics = input.generics.clone();
+
+    // Like the standard derives, every type parameter is required to implement
+    // the trait being derived.
+    let params = input
+        .generics
+        .type_params()
+        .map(|x| x.ident.clone())
+        .collect::<Vec<_>>();
+
+    for param in params {
+        generics
+            .make_where_clause()
+            .predicates
+            .push(parse_quote!(#param: #crate_ident::Html<#buf>));
+    }
+
+    generics
+        .params
+        .push(GenericParam::Type(TypeParam::from(buf.clone())));
+    generics
+        .make_where_clause()
+        .predicates
+        .push(parse_quote!(#buf: #crate_ident::Buffer + ?::core::marker::Sized));
+
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+
+    let completions = crate::completion::to_token_stream(&crate_ident);
+    let warnings = crate::diagnostic::to_token_stream();
+
+    Ok(quote! {
+        #builder
+
+        impl #impl_generics #crate_ident::Html<#buf> for #ident #ty_generics #where_clause {
+            #[inline]
+            fn write(self, #write_ident: &mut #buf) {
+                #completions
+                #warnings
+
+                #body
+            }
+        }
+    })
+}
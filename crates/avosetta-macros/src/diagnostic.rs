@@ -0,0 +1,47 @@
+use std::cell::RefCell;
+
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{ToTokens, quote_spanned};
+
+thread_local! {
+    static WARNINGS: RefCell<Vec<(Span, String)>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Reports a warning at `span` when the current macro expands.
+#[inline]
+pub fn warn(span: Span, message: String) {
+    WARNINGS.with_borrow_mut(|x| x.push((span, message)));
+}
+
+#[inline]
+pub fn clear() {
+    WARNINGS.with_borrow_mut(|x| x.clear());
+}
+
+/// Returns tokens that report the collected warnings.
+///
+/// Procedural macros cannot emit warnings on stable Rust, so each warning is
+/// reported as the use of a deprecated item whose note holds the message.
+pub fn to_token_stream() -> TokenStream {
+    WARNINGS.with_borrow(|warnings| {
+        let mut tokens = TokenStream::new();
+
+        for (span, message) in warnings {
+            let ident = Ident::new("avosetta_lint", Span::mixed_site());
+            let usage = Ident::new("avosetta_lint", span.resolved_at(Span::mixed_site()));
+
+            quote_spanned! {*span=>
+                {
+                    #[deprecated(note = #message)]
+                    #[allow(non_camel_case_types)]
+                    struct #ident;
+
+                    let _ = #usage;
+                }
+            }
+            .to_tokens(&mut tokens);
+        }
+
+        tokens
+    })
+}
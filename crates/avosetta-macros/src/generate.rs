@@ -1,9 +1,21 @@
+use std::collections::HashSet;
+
 use proc_macro2::{Ident, Span, TokenStream};
-use quote::{ToTokens, quote};
-use syn::{Expr, ExprLit, Lit, LitStr};
+use quote::{ToTokens, format_ident, quote, quote_spanned};
+use syn::{Expr, ExprLit, Lit, LitStr, spanned::Spanned};
 
 use crate::ast::*;
 
+/// The number of bytes reserved for a value whose length is only known at
+/// runtime, such as an interpolated expression or a component.
+const DYNAMIC_LEN: usize = 24;
+
+/// The number of bytes reserved for a `@defer` block's placeholder.
+const DEFER_LEN: usize = 64;
+
+/// The number of bytes reserved for a spread of runtime attributes.
+const SPREAD_LEN: usize = 16;
+
 pub struct Stream {
     estimated_len: usize,
     buf: String,
@@ -11,11 +23,57 @@ pub struct Stream {
 
     crate_ident: CrateIdent,
     write_ident: Ident,
+    asynchronous: bool,
+    options: Options,
+    context: Context,
+
+    /// The end of the static text written so far in the body of a `<script>` or
+    /// `<style>` element, used to find a closing tag split across literals.
+    raw_text: String,
+}
+
+/// The kind of content being generated, which decides how text is escaped.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Context {
+    Normal,
+
+    /// The body of a `<script>` or `<style>` element, which is written without
+    /// entity escaping.
+    RawText(String),
+
+    /// The body of a `<textarea>` or `<title>` element, which holds escaped text
+    /// but no elements.
+    EscapableRawText(String),
+
+    /// The body of an `<svg>` or `<math>` element, whose elements and
+    /// attributes are not HTML.
+    Foreign,
+}
+
+impl Context {
+    fn for_element(name: &str) -> Self {
+        let name = name.to_ascii_lowercase();
+
+        match name.as_str() {
+            "script" | "style" => Self::RawText(name),
+            "textarea" | "title" => Self::EscapableRawText(name),
+            "svg" | "math" => Self::Foreign,
+            _ => Self::Normal,
+        }
+    }
+
+    /// Returns the context for the body of the element `name` in this context.
+    fn enter(&self, name: &str) -> Self {
+        match self {
+            Self::Foreign => Self::Foreign,
+            _ => Self::for_element(name),
+        }
+    }
 }
 
 impl Stream {
     #[inline]
-    pub fn new(crate_ident: CrateIdent) -> Self {
+    pub fn new(crate_ident: CrateIdent, options: Options) -> Self {
         Self {
             estimated_len: 0,
             buf: String::new(),
@@ -23,6 +81,10 @@ impl Stream {
 
             crate_ident,
             write_ident: Ident::new("__s", Span::mixed_site()),
+            asynchronous: false,
+            options,
+            context: Context::Normal,
+            raw_text: String::new(),
         }
     }
 
@@ -37,23 +99,9 @@ impl Stream {
         self.estimated_len += s.len();
     }
 
+    #[inline]
     pub fn push_escaped(&mut self, s: &str) {
-        for ch in s.chars() {
-            match ch {
-                '&' => self.push_raw("&amp;"),
-
-                '<' => self.push_raw("&lt;"),
-                '>' => self.push_raw("&gt;"),
-
-                '\'' => self.push_raw("&apos;"),
-                '\"' => self.push_raw("&quot;"),
-
-                ch => {
-                    self.buf.push(ch);
-                    self.estimated_len += ch.len_utf8();
-                }
-            }
-        }
+        self.push_raw(&escape(s));
     }
 
     #[inline]
@@ -70,8 +118,54 @@ impl Stream {
     where
         T: ToTokens,
     {
-        let (crate_ident, write_ident) = self.idents();
-        self.push_tokens(quote! { #crate_ident::Html::write(#tokens, #write_ident); });
+        let (crate_ident, _) = self.idents();
+        let target = &self.write_ident;
+
+        if self.options.reusable {
+            self.push_tokens(quote! { #crate_ident::HtmlRef::write_ref(&(#tokens), #target); });
+        } else {
+            self.push_tokens(quote! { #crate_ident::Html::write(#tokens, #target); });
+        }
+    }
+
+    #[inline]
+    pub fn push_write_async<T>(&mut self, tokens: T)
+    where
+        T: ToTokens,
+    {
+        let (crate_ident, _) = self.idents();
+        let target = &self.write_ident;
+
+        if self.options.send {
+            self.push_tokens(
+                quote! { #crate_ident::SendAsyncHtml::write_send(#tokens, #target).await; },
+            );
+        } else {
+            self.push_tokens(
+                quote! { #crate_ident::AsyncHtml::write_async(#tokens, #target).await; },
+            );
+        }
+
+        self.asynchronous = true;
+    }
+
+    #[inline]
+    pub fn push_error(&mut self, err: syn::Error) {
+        self.push_tokens(err.into_compile_error());
+    }
+
+    /// Returns tokens for a value captured by the template, borrowing it in
+    /// reusable templates so that the template can be written more than once.
+    #[inline]
+    pub fn capture<T>(&self, tokens: T) -> TokenStream
+    where
+        T: ToTokens,
+    {
+        if self.options.reusable {
+            quote! { &(#tokens) }
+        } else {
+            tokens.into_token_stream()
+        }
     }
 
     pub fn push_scope<F>(&mut self, f: F)
@@ -84,16 +178,108 @@ impl Stream {
             tokens: TokenStream::new(),
             crate_ident: self.crate_ident.clone(),
             write_ident: self.write_ident.clone(),
+            asynchronous: self.asynchronous,
+            options: self.options.clone(),
+            context: self.context.clone(),
+            raw_text: std::mem::take(&mut self.raw_text),
         };
 
         (f)(&mut stream);
         stream.flush();
 
         self.estimated_len += stream.estimated_len;
+        self.asynchronous |= stream.asynchronous;
+        self.raw_text = stream.raw_text;
         let tokens = stream.tokens;
         self.push_tokens(quote! { { #tokens } });
     }
 
+    /// Generates the body of a `<script>` or `<style>` element, writing any
+    /// dynamic content through a single `RawTextBuffer` so that escaping sees
+    /// the text on both sides of each write.
+    fn push_raw_text<F>(&mut self, f: F)
+    where
+        F: FnOnce(&mut Self),
+    {
+        let mut stream = Self {
+            estimated_len: 0,
+            buf: String::new(),
+            tokens: TokenStream::new(),
+            crate_ident: self.crate_ident.clone(),
+            write_ident: self.write_ident.clone(),
+            asynchronous: self.asynchronous,
+            options: self.options.clone(),
+            context: self.context.clone(),
+            raw_text: String::new(),
+        };
+
+        (f)(&mut stream);
+
+        self.estimated_len += stream.estimated_len;
+        self.asynchronous |= stream.asynchronous;
+
+        // A body without dynamic content is written as it is.
+        if stream.tokens.is_empty() {
+            self.buf.push_str(&stream.buf);
+            return;
+        }
+
+        stream.flush();
+
+        let (crate_ident, write_ident) = self.idents();
+        let tokens = stream.tokens;
+
+        self.push_tokens(quote! {{
+            let #write_ident = &mut #crate_ident::RawTextBuffer::new(#write_ident);
+            #tokens
+        }});
+    }
+
+    pub fn push_template<F>(&mut self, asynchronous: bool, f: F) -> TokenStream
+    where
+        F: FnOnce(&mut Self),
+    {
+        let mut stream = Self::new(self.crate_ident.clone(), self.options.clone());
+        stream.asynchronous = asynchronous;
+        stream.options.nested = true;
+
+        (f)(&mut stream);
+        stream.into_token_stream()
+    }
+
+    /// Generates a template passed to a component, which renders it with
+    /// [`Html`]. A template containing `@await` is rendered into a string
+    /// first, making this template asynchronous.
+    pub fn push_children<F>(&mut self, f: F) -> TokenStream
+    where
+        F: FnOnce(&mut Self),
+    {
+        let mut stream = Self::new(self.crate_ident.clone(), self.options.clone());
+        stream.options.nested = true;
+
+        (f)(&mut stream);
+
+        if !stream.asynchronous {
+            return stream.into_token_stream();
+        }
+
+        let crate_ident = self.idents().0.clone();
+        let template = stream.into_token_stream();
+        let write = if self.options.send {
+            quote! { #crate_ident::SendAsyncHtml::write_send }
+        } else {
+            quote! { #crate_ident::AsyncHtml::write_async }
+        };
+
+        self.asynchronous = true;
+
+        quote! {{
+            let mut s = ::std::string::String::new();
+            #write(#template, &mut s).await;
+            #crate_ident::Raw(s)
+        }}
+    }
+
     pub fn flush(&mut self) {
         let (crate_ident, write_ident) = self.idents();
 
@@ -115,32 +301,155 @@ impl Stream {
             tokens,
             crate_ident,
             write_ident,
+            asynchronous,
+            options,
             ..
         } = self;
 
-        quote! {{
-            #[inline]
-            const fn coerce<T: #crate_ident::Html>(x: T) -> impl #crate_ident::Html { x }
+        if options.reusable {
+            // A reusable template nested in another is created each time the
+            // outer template is written, so it borrows the outer template's
+            // captures instead of moving them.
+            let move_token = if options.nested {
+                quote! {}
+            } else {
+                quote! { move }
+            };
+
+            return quote! {{
+                #[derive(Clone, Copy)]
+                struct HtmlRef<F>(F);
+
+                impl<F, B> #crate_ident::HtmlRef<B> for HtmlRef<F>
+                where
+                    F: ::core::ops::Fn(&mut B),
+                    B: #crate_ident::Buffer + ?::core::marker::Sized,
+                {
+                    #[inline]
+                    fn write_ref(&self, s: &mut B) { (self.0)(s) }
+                }
+
+                impl<F, B> #crate_ident::Html<B> for HtmlRef<F>
+                where
+                    F: ::core::ops::Fn(&mut B),
+                    B: #crate_ident::Buffer + ?::core::marker::Sized,
+                {
+                    #[inline]
+                    fn write(self, s: &mut B) { (self.0)(s) }
+                }
+
+                #[inline]
+                const fn coerce<F, B, N>(f: F, _: &N) -> HtmlRef<F>
+                where
+                    F: ::core::ops::Fn(&mut B),
+                    B: #crate_ident::Buffer + ?::core::marker::Sized,
+                    N: #crate_ident::__DefaultBuffer<B>,
+                {
+                    HtmlRef(f)
+                }
+
+                coerce(#move_token |#write_ident| {
+                    #crate_ident::Buffer::reserve(#write_ident, #estimated_len);
+
+                    #tokens
+                }, &0)
+            }};
+        }
+
+        // A `Send` template boxes its future, since the future of an async
+        // closure cannot be required to be `Send`.
+        if asynchronous && options.send {
+            return quote! {{
+                #[inline]
+                const fn coerce<F, B, N>(f: F, _: &N) -> #crate_ident::__SendAsyncHtml<F>
+                where
+                    F: ::core::ops::FnOnce(&mut B) -> #crate_ident::__SendFuture<'_>,
+                    B: #crate_ident::Buffer + ::core::marker::Send + ?::core::marker::Sized,
+                    N: #crate_ident::__DefaultBuffer<B>,
+                {
+                    #crate_ident::__SendAsyncHtml(f)
+                }
+
+                coerce(move |#write_ident| ::std::boxed::Box::pin(async move {
+                    #crate_ident::Buffer::reserve(#write_ident, #estimated_len);
 
+                    #tokens
+                }), &0)
+            }};
+        }
+
+        if asynchronous {
+            return quote! {{
+                #[inline]
+                const fn coerce<F, B, N>(f: F, _: &N) -> #crate_ident::__AsyncHtml<F>
+                where
+                    F: ::core::ops::AsyncFnOnce(&mut B),
+                    B: #crate_ident::Buffer + ?::core::marker::Sized,
+                    N: #crate_ident::__DefaultBuffer<B>,
+                {
+                    #crate_ident::__AsyncHtml(f)
+                }
+
+                coerce(async move |#write_ident| {
+                    #crate_ident::Buffer::reserve(#write_ident, #estimated_len);
+
+                    #tokens
+                }, &0)
+            }};
+        }
+
+        quote! {{
             struct Html<F>(F);
 
-            impl<F> #crate_ident::Html for Html<F>
+            impl<F, B> #crate_ident::Html<B> for Html<F>
             where
-                F: ::core::ops::FnOnce(&mut ::std::string::String)
+                F: ::core::ops::FnOnce(&mut B),
+                B: #crate_ident::Buffer + ?::core::marker::Sized,
             {
                 #[inline]
-                fn write(self, s: &mut ::std::string::String) { (self.0)(s) }
+                fn write(self, s: &mut B) { (self.0)(s) }
+            }
+
+            #[inline]
+            const fn coerce<F, B, N>(f: F, _: &N) -> impl #crate_ident::Html<B>
+            where
+                F: ::core::ops::FnOnce(&mut B),
+                B: #crate_ident::Buffer + ?::core::marker::Sized,
+                N: #crate_ident::__DefaultBuffer<B>,
+            {
+                Html(f)
             }
 
-            coerce(Html(move |#write_ident: &mut ::std::string::String| {
-                ::std::string::String::reserve(#write_ident, #estimated_len);
+            coerce(move |#write_ident| {
+                #crate_ident::Buffer::reserve(#write_ident, #estimated_len);
 
                 #tokens
-            }))
+            }, &0)
         }}
     }
 }
 
+/// Escapes `s` for HTML text and attribute values.
+fn escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+
+    for ch in s.chars() {
+        match ch {
+            '&' => out.push_str("&amp;"),
+
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+
+            '\'' => out.push_str("&apos;"),
+            '"' => out.push_str("&quot;"),
+
+            ch => out.push(ch),
+        }
+    }
+
+    out
+}
+
 pub trait Generate {
     fn generate(&self, stream: &mut Stream);
 }
@@ -186,27 +495,121 @@ impl Generate for Element {
         match self {
             Element::Normal(x) => x.generate(stream),
             Element::Void(x) => x.generate(stream),
+            Element::Component(x) => x.generate(stream),
+        }
+    }
+}
+
+/// Reports an error if elements are not allowed in the current context.
+fn check_element_allowed(stream: &mut Stream, span: Span) -> bool {
+    match &stream.context {
+        Context::Normal | Context::Foreign => true,
+
+        Context::RawText(name) | Context::EscapableRawText(name) => {
+            let message = format!("`<{name}>` can only contain text, not elements");
+            stream.push_error(syn::Error::new(span, message));
+            false
         }
     }
 }
 
+/// Warns about unknown element and attribute names if `#![check_names]` is set.
+fn check_names(stream: &Stream, name: &Name, attrs: &Attrs) {
+    if !stream.options.check_names || stream.context == Context::Foreign {
+        return;
+    }
+
+    let value = name.value();
+    crate::names::check_element(&value, name.span());
+
+    // Custom elements define their own attributes, and the attributes of
+    // `<svg>` and `<math>` are not HTML attributes either.
+    if value.contains('-') || Context::for_element(&value) == Context::Foreign {
+        return;
+    }
+
+    for attr in attrs.named() {
+        crate::names::check_attr(&attr.name.value(), attr.name.span());
+    }
+}
+
 impl Generate for Normal {
     fn generate(&self, stream: &mut Stream) {
+        if !check_element_allowed(stream, self.name.span()) {
+            return;
+        }
+
+        let name = self.name.value();
+
+        // A void element with an empty block is written without an end tag.
+        let void = stream.context != Context::Foreign && crate::names::is_void(&name);
+
+        if void && !self.inner.0.is_empty() {
+            let message = format!(
+                "`{name}` is a void element and cannot have children; end it with `;` instead of a block"
+            );
+
+            return stream.push_error(syn::Error::new(self.brace.span.join(), message));
+        }
+
+        if void {
+            let message = format!("`{name}` is a void element; end it with `;` instead of `{{}}`");
+            crate::diagnostic::warn(self.brace.span.join(), message);
+        }
+
+        check_names(stream, &self.name, &self.attrs);
+
         stream.push_raw("<");
         self.name.generate(stream);
         self.attrs.generate(stream);
         stream.push_raw(">");
 
-        self.inner.generate(stream);
+        let context = stream.context.enter(&name);
+        let context = std::mem::replace(&mut stream.context, context);
 
-        stream.push_raw("</");
-        self.name.generate(stream);
-        stream.push_raw(">");
+        if let Context::RawText(_) = stream.context {
+            stream.push_raw_text(|stream| self.inner.generate(stream));
+        } else {
+            self.inner.generate(stream);
+        }
+
+        stream.context = context;
+
+        if !void {
+            stream.push_raw("</");
+            self.name.generate(stream);
+            stream.push_raw(">");
+        }
     }
 }
 
 impl Generate for Void {
     fn generate(&self, stream: &mut Stream) {
+        if !check_element_allowed(stream, self.name.span()) {
+            return;
+        }
+
+        let name = self.name.value();
+
+        // Elements in `<svg>` and `<math>` may be self-closing instead.
+        if stream.context == Context::Foreign {
+            stream.push_raw("<");
+            self.name.generate(stream);
+            self.attrs.generate(stream);
+            stream.push_raw("/>");
+            return;
+        }
+
+        if crate::names::is_element(&name) && !crate::names::is_void(&name) {
+            let message = format!(
+                "`{name}` is not a void element and needs an end tag; write `{{}}` instead of `;`"
+            );
+
+            return stream.push_error(syn::Error::new(self.semi_token.span, message));
+        }
+
+        check_names(stream, &self.name, &self.attrs);
+
         stream.push_raw("<");
         self.name.generate(stream);
         self.attrs.generate(stream);
@@ -214,18 +617,91 @@ impl Generate for Void {
     }
 }
 
+impl Generate for Component {
+    fn generate(&self, stream: &mut Stream) {
+        let path = &self.path;
+
+        if !check_element_allowed(stream, path.span()) {
+            return;
+        }
+
+        let mut args = TokenStream::new();
+        let mut seen = HashSet::new();
+
+        for prop in &self.props {
+            let name = &prop.name;
+
+            if !seen.insert(name.to_string()) {
+                return stream.push_error(syn::Error::new(
+                    name.span(),
+                    format!("duplicate prop `{name}`"),
+                ));
+            }
+
+            let expr = stream.capture(&prop.expr);
+            args.extend(quote! { .#name(#expr) });
+        }
+
+        if let Some(children) = &self.children {
+            let mut slots = HashSet::new();
+
+            for slot in &children.slots {
+                let name = &slot.name;
+
+                if !slots.insert(name.to_string()) {
+                    return stream.push_error(syn::Error::new(
+                        name.span(),
+                        format!("duplicate slot `{name}`"),
+                    ));
+                }
+
+                let method = format_ident!("__slot_{}", name, span = name.span());
+                let template = stream.push_children(|stream| slot.group.generate(stream));
+                args.extend(quote! { .#method(#template) });
+            }
+
+            if !children.group.0.is_empty() || children.slots.is_empty() {
+                if seen.contains("children") {
+                    return stream.push_error(syn::Error::new(
+                        self.path.span(),
+                        "children are passed both as a prop and as a block",
+                    ));
+                }
+
+                let template = stream.push_children(|stream| children.group.generate(stream));
+                args.extend(quote! { .children(#template) });
+            }
+        }
+
+        // The component is built afresh on every write, so it is written by
+        // value even in reusable templates.
+        let (crate_ident, write_ident) = stream.idents();
+        let build = quote_spanned! { path.span()=> __build() };
+
+        stream.push_tokens(quote! {
+            #crate_ident::Html::write(#path::__builder() #args .#build, #write_ident);
+        });
+
+        stream.estimated_len += DYNAMIC_LEN;
+    }
+}
+
 impl Generate for Interp {
     fn generate(&self, stream: &mut Stream) {
         match &self.value {
             InterpValue::Match(x) => x.generate(stream),
             InterpValue::If(x) => x.generate(stream),
             InterpValue::For(x) => x.generate(stream),
+            InterpValue::Await(x) => x.generate(stream),
+            InterpValue::Defer(x) => x.generate(stream),
+            InterpValue::Block(x) => x.generate(stream),
+            InterpValue::Extends(x) => x.generate(stream),
 
             InterpValue::Expr(Expr::Lit(ExprLit { lit, .. })) => lit.generate(stream),
 
             InterpValue::Expr(expr) => {
                 stream.push_write(expr);
-                stream.estimated_len += 24;
+                stream.estimated_len += DYNAMIC_LEN;
             }
 
             InterpValue::Stmt(stmt) => stream.push_tokens(stmt),
@@ -233,6 +709,91 @@ impl Generate for Interp {
     }
 }
 
+impl Generate for InterpAwait {
+    #[inline]
+    fn generate(&self, stream: &mut Stream) {
+        if stream.options.reusable {
+            return stream.push_error(syn::Error::new_spanned(
+                self.await_token,
+                "`@await` is not supported in reusable templates",
+            ));
+        }
+
+        let expr = &self.expr;
+
+        stream.push_write_async(expr);
+        stream.estimated_len += DYNAMIC_LEN;
+    }
+}
+
+impl Generate for InterpDefer {
+    fn generate(&self, stream: &mut Stream) {
+        if stream.options.reusable {
+            return stream.push_error(syn::Error::new_spanned(
+                self.defer_token,
+                "`@defer` is not supported in reusable templates",
+            ));
+        }
+
+        let suspense = &self.suspense;
+
+        let body = stream.push_template(true, |stream| self.body.generate(stream));
+        let fallback = match &self.fallback {
+            Some(x) => stream.push_template(false, |stream| x.group.generate(stream)),
+            None => quote! { () },
+        };
+
+        let (crate_ident, _) = stream.idents();
+
+        stream.push_write(quote! {
+            #crate_ident::Suspense::defer(
+                #suspense,
+                async move {
+                    let mut s = ::std::string::String::new();
+                    #crate_ident::AsyncHtml::write_async(#body, &mut s).await;
+                    #crate_ident::Raw(s)
+                },
+                #fallback,
+            )
+        });
+
+        stream.estimated_len += DEFER_LEN;
+    }
+}
+
+impl Generate for InterpBlock {
+    #[inline]
+    fn generate(&self, stream: &mut Stream) {
+        // Blocks are only meaningful in a layout, where they are substituted
+        // before the template is parsed.
+        if stream.options.layout.is_none() {
+            return stream.push_error(syn::Error::new_spanned(
+                self.block_token,
+                "`@block` can only be used in a layout declared with `layout!`",
+            ));
+        }
+
+        self.group.generate(stream);
+    }
+}
+
+impl Generate for InterpExtends {
+    #[inline]
+    fn generate(&self, stream: &mut Stream) {
+        let Self { path, body, .. } = self;
+        let options = &stream.options.tokens;
+
+        let nested = if stream.options.reusable {
+            quote! { #![__nested] }
+        } else {
+            quote! {}
+        };
+
+        stream.push_write(quote! { #path! { #options #nested #body } });
+        stream.estimated_len += DYNAMIC_LEN;
+    }
+}
+
 impl Generate for InterpMatch {
     #[inline]
     fn generate(&self, stream: &mut Stream) {
@@ -334,28 +895,367 @@ impl Generate for Name {
 
 impl Generate for Attrs {
     fn generate(&self, stream: &mut Stream) {
-        #[inline]
-        fn is_inert(attr: &Attr) -> bool {
-            attr.value.as_ref().is_none_or(|x| {
-                matches!(
-                    x,
-                    AttrValue {
-                        expr: Expr::Lit(_),
-                        ..
+        let attrs = match self.merge() {
+            Ok(attrs) => attrs,
+            Err(err) => return stream.push_error(err),
+        };
+
+        // Attributes are written in source order. Adjacent static attributes
+        // still end up in a single write, since raw text is buffered until the
+        // next dynamic value.
+        for attr in attrs.iter().filter(|x| !x.is_empty()) {
+            // Spreads write the space before each of their attributes, since
+            // they may write none at all.
+            if !matches!(attr, Merged::Spread(_)) {
+                stream.push_raw(" ");
+            }
+
+            attr.generate(stream);
+        }
+    }
+}
+
+/// An attribute after repeated `class` and `style` attributes are merged.
+enum Merged<'a> {
+    Single(&'a Attr),
+    Spread(&'a Spread),
+
+    Joined {
+        name: &'a Name,
+        separator: &'static str,
+        parts: Vec<Part<'a>>,
+    },
+}
+
+/// A value of a merged attribute.
+enum Part<'a> {
+    Value(&'a Expr),
+    Toggle(&'a Toggle),
+    Style(&'a StyleMap),
+}
+
+impl Merged<'_> {
+    /// Returns `true` for a merged attribute whose values are all left out, so
+    /// that the attribute is left out as well.
+    fn is_empty(&self) -> bool {
+        match self {
+            Merged::Joined { parts, .. } => parts.iter().all(Part::is_empty),
+            Merged::Single(_) | Merged::Spread(_) => false,
+        }
+    }
+}
+
+impl Part<'_> {
+    /// Returns `true` for `false`, empty strings and empty style maps, which
+    /// would only add stray separators.
+    fn is_empty(&self) -> bool {
+        match self {
+            Part::Value(Expr::Lit(ExprLit {
+                lit: Lit::Bool(x), ..
+            })) => !x.value,
+
+            Part::Value(Expr::Lit(ExprLit {
+                lit: Lit::Str(x), ..
+            })) => x.value().is_empty(),
+
+            Part::Style(x) => x.decls.is_empty(),
+
+            _ => false,
+        }
+    }
+}
+
+impl Attrs {
+    /// Returns the attributes that are written by name, skipping spreads,
+    /// class toggles and style maps.
+    pub fn named(&self) -> impl Iterator<Item = &Attr> {
+        self.inner.iter().filter_map(|x| match x {
+            AttrItem::Attr(x) => Some(x),
+            AttrItem::Toggle(_) | AttrItem::Style(_) | AttrItem::Spread(_) => None,
+        })
+    }
+
+    /// Merges repeated `class` attributes and class toggles with spaces, and
+    /// repeated `style` attributes with semicolons, in the position of the
+    /// first one.
+    ///
+    /// Any other repeated attribute is an error, since browsers keep only the
+    /// first.
+    fn merge(&self) -> syn::Result<Vec<Merged<'_>>> {
+        let mut merged = Vec::<Merged>::new();
+        let mut names = Vec::<(String, usize)>::new();
+
+        for item in &self.inner {
+            let name = match item {
+                AttrItem::Attr(x) => &x.name,
+                AttrItem::Toggle(x) => &x.class,
+                AttrItem::Style(x) => &x.style,
+
+                // Names in a spread are only known at runtime, so repeats of
+                // them are not detected.
+                AttrItem::Spread(x) => {
+                    merged.push(Merged::Spread(x));
+                    continue;
+                }
+            };
+
+            let value = name.value().to_ascii_lowercase();
+
+            let separator = match value.as_str() {
+                "class" => Some(" "),
+                "style" => Some(";"),
+                _ => None,
+            };
+
+            let Some(&(_, i)) = names.iter().find(|(x, _)| *x == value) else {
+                names.push((value, merged.len()));
+
+                merged.push(match (item, separator) {
+                    (AttrItem::Attr(x), _) => Merged::Single(x),
+
+                    (_, separator) => Merged::Joined {
+                        name,
+                        separator: separator.unwrap_or_default(),
+                        parts: vec![part(item)?],
+                    },
+                });
+
+                continue;
+            };
+
+            let Some(separator) = separator else {
+                let message =
+                    format!("duplicate attribute `{value}`; browsers keep only the first");
+                return Err(syn::Error::new(name.span(), message));
+            };
+
+            if let Merged::Single(first) = merged[i] {
+                merged[i] = Merged::Joined {
+                    name: &first.name,
+                    separator,
+                    parts: vec![value_of(first)?],
+                };
+            }
+
+            if let Merged::Joined { parts, .. } = &mut merged[i] {
+                parts.push(part(item)?);
+            }
+        }
+
+        Ok(merged)
+    }
+}
+
+/// Returns an attribute, class toggle or style map as part of a merged
+/// attribute.
+fn part(item: &AttrItem) -> syn::Result<Part<'_>> {
+    match item {
+        AttrItem::Attr(x) => value_of(x),
+        AttrItem::Toggle(x) => Ok(Part::Toggle(x)),
+        AttrItem::Style(x) => {
+            for decl in &x.decls {
+                let property = decl.property.value();
+
+                let valid = !property.is_empty()
+                    && property
+                        .bytes()
+                        .all(|x| x.is_ascii_alphanumeric() || matches!(x, b'-' | b'_'));
+
+                if !valid {
+                    let message = format!("`{property}` is not a valid CSS property name");
+                    return Err(syn::Error::new(decl.property.span(), message));
+                }
+            }
+
+            Ok(Part::Style(x))
+        }
+        AttrItem::Spread(_) => unreachable!("spreads are never merged"),
+    }
+}
+
+/// Returns the value of a repeated attribute, which cannot be left out.
+fn value_of(attr: &Attr) -> syn::Result<Part<'_>> {
+    match &attr.value {
+        Some(x) => Ok(Part::Value(&x.expr)),
+
+        None => {
+            let message = format!("repeated `{}` attributes need a value", attr.name.value());
+            Err(syn::Error::new(attr.name.span(), message))
+        }
+    }
+}
+
+impl Generate for Merged<'_> {
+    fn generate(&self, stream: &mut Stream) {
+        let (name, separator, parts) = match self {
+            Merged::Single(attr) => return attr.generate(stream),
+            Merged::Spread(spread) => return spread.generate(stream),
+
+            Merged::Joined {
+                name,
+                separator,
+                parts,
+            } => (name, separator, parts),
+        };
+
+        let parts = parts.iter().filter(|x| !x.is_empty());
+
+        let is_static = |x: &&Part| match x {
+            Part::Value(x) => matches!(x, Expr::Lit(_)),
+            Part::Toggle(_) => false,
+            Part::Style(x) => x.decls.iter().all(|x| css_literal(&x.value).is_some()),
+        };
+
+        if parts.clone().all(|x| is_static(&x)) {
+            name.generate(stream);
+            stream.push_raw("=\"");
+
+            for (i, part) in parts.enumerate() {
+                if i > 0 {
+                    stream.push_raw(separator);
+                }
+
+                match part {
+                    Part::Value(Expr::Lit(ExprLit { lit, .. })) => lit.generate(stream),
+
+                    Part::Style(x) => {
+                        let css = x.decls.iter().filter_map(|x| {
+                            Some(format!("{}:{}", x.property.value(), css_literal(&x.value)?))
+                        });
+
+                        stream.push_escaped(&css.collect::<Vec<_>>().join(";"));
                     }
-                )
-            })
+
+                    _ => unreachable!(),
+                }
+            }
+
+            stream.push_raw("\"");
+            return;
         }
 
-        for attr in self.inner.iter().filter(|x| is_inert(x)) {
-            stream.push_raw(" ");
-            attr.generate(stream);
+        let crate_ident = stream.idents().0.clone();
+        let name = name.value();
+
+        // Class literals and toggles are collected into one `ClassList`, so
+        // that names are not repeated and an empty list leaves out the
+        // attribute. Other values are joined after it.
+        let toggles = parts.clone().any(|x| matches!(x, Part::Toggle(_)));
+        let mut list = quote! { #crate_ident::ClassList::new() };
+        let mut values = Vec::new();
+
+        for part in parts {
+            match part {
+                Part::Value(Expr::Lit(ExprLit {
+                    lit: Lit::Str(x), ..
+                })) if toggles => list = quote! { #list.with(#x) },
+
+                Part::Toggle(Toggle { name, cond, .. }) => {
+                    let name = name.value();
+                    let cond = stream.capture(cond);
+
+                    let cond = if stream.options.reusable {
+                        quote! { *#cond }
+                    } else {
+                        cond
+                    };
+
+                    list = quote! { #list.toggle(#name, #cond) };
+                }
+
+                Part::Style(x) => values.push(style(stream, x)),
+                Part::Value(x) => values.push(stream.capture(x)),
+            }
         }
 
-        for attr in self.inner.iter().filter(|x| !is_inert(x)) {
-            stream.push_raw(" ");
-            attr.generate(stream);
+        if toggles {
+            values.insert(0, list);
+        }
+
+        let expr = values
+            .into_iter()
+            .rev()
+            .reduce(|z, a| quote! { #crate_ident::__Join(#separator, #a, #z) })
+            .unwrap_or_default();
+
+        stream.push_write(quote! {
+            #crate_ident::__Attr(
+                #name,
+                #expr,
+                const { #crate_ident::EscapeContext::for_attribute(#name) },
+            )
+        });
+
+        stream.estimated_len += name.len();
+        stream.estimated_len += 3;
+    }
+}
+
+/// Returns tokens that build a `Style` from a style map. Runs of literal
+/// declarations are written at compile time.
+fn style(stream: &Stream, map: &StyleMap) -> TokenStream {
+    let crate_ident = stream.idents().0;
+    let mut tokens = quote! { #crate_ident::Style::new() };
+    let mut run = Vec::new();
+
+    for decl in &map.decls {
+        let property = decl.property.value();
+
+        if let Some(value) = css_literal(&decl.value) {
+            run.push(escape(&format!("{property}:{value}")));
+            continue;
         }
+
+        if !run.is_empty() {
+            let css = run.join(";");
+            tokens = quote! { #tokens.__trusted(#css) };
+            run.clear();
+        }
+
+        let value = stream.capture(&decl.value);
+
+        let value = if stream.options.reusable {
+            quote! { #crate_ident::ByRef(#value) }
+        } else {
+            value
+        };
+
+        tokens = quote! { #tokens.with(#property, #value) };
+    }
+
+    if !run.is_empty() {
+        let css = run.join(";");
+        tokens = quote! { #tokens.__trusted(#css) };
+    }
+
+    tokens
+}
+
+/// Returns the CSS text of a literal style value, such as `"red"`, `0` or
+/// `1.5rem`.
+///
+/// A number's suffix is written as its unit. Rust reads `1.5em` and `2ex` as
+/// malformed exponents, so those units can only be written in a string.
+fn css_literal(expr: &Expr) -> Option<String> {
+    match expr {
+        Expr::Lit(ExprLit { lit, .. }) => match lit {
+            Lit::Str(x) => Some(x.value()),
+            Lit::Int(x) => Some(format!("{}{}", x.base10_digits(), x.suffix())),
+            Lit::Float(x) => Some(format!("{}{}", x.base10_digits(), x.suffix())),
+            _ => None,
+        },
+
+        _ => None,
+    }
+}
+
+impl Generate for Spread {
+    fn generate(&self, stream: &mut Stream) {
+        let crate_ident = stream.idents().0.clone();
+        let expr = stream.capture(&self.expr);
+
+        stream.push_write(quote! { #crate_ident::Attrs(#expr) });
+        stream.estimated_len += SPREAD_LEN;
     }
 }
 
@@ -396,13 +1296,49 @@ impl Generate for Attr {
             }
 
             Some(AttrValue { expr, .. }) => {
-                let name = match &self.name {
-                    Name::Lit(x) => x.value(),
-                    Name::Ident(x) => x.to_string(),
-                };
+                let name = self.name.value();
+
+                let crate_ident = stream.idents().0.clone();
+                let span = expr.span();
+                let mut expr = stream.capture(expr);
+
+                if stream.options.check_urls && is_url_attr(&name) {
+                    let value = Ident::new("__url", Span::mixed_site());
+
+                    let target = if stream.options.reusable {
+                        quote! { #value }
+                    } else {
+                        quote! { &#value }
+                    };
+
+                    let check = quote_spanned! {span=>
+                        #crate_ident::__UrlCheck(#target).check()
+                    };
+
+                    let tokens = quote! {
+                        let #value = #expr;
+
+                        #[cfg(debug_assertions)]
+                        {
+                            #[allow(unused_imports)]
+                            use #crate_ident::__UrlCheckAny as _;
+                            #check;
+                        }
+                    };
+
+                    stream.push_tokens(tokens);
+                    expr = value.into_token_stream();
+                }
 
-                let (crate_ident, _) = stream.idents();
-                stream.push_write(quote! { #crate_ident::Attr(#name, #expr) });
+                // The escaping context depends only on the name, so it is chosen
+                // at compile time.
+                stream.push_write(quote! {
+                    #crate_ident::__Attr(
+                        #name,
+                        #expr,
+                        const { #crate_ident::EscapeContext::for_attribute(#name) },
+                    )
+                });
 
                 stream.estimated_len += name.len();
                 stream.estimated_len += 3;
@@ -411,13 +1347,20 @@ impl Generate for Attr {
     }
 }
 
+/// Returns `true` if `#![check_urls]` checks the value of the attribute `name`.
+fn is_url_attr(name: &str) -> bool {
+    ["href", "src", "action", "formaction"]
+        .iter()
+        .any(|x| name.eq_ignore_ascii_case(x))
+}
+
 impl Generate for Lit {
     fn generate(&self, stream: &mut Stream) {
         match self {
             Lit::Str(x) => x.generate(stream),
 
             Lit::Byte(x) => stream.push_raw(&x.value().to_string()),
-            Lit::Char(x) => stream.push_raw(&x.value().to_string()),
+            Lit::Char(x) => push_text(stream, &x.value().to_string(), x.span()),
             Lit::Int(x) => stream.push_raw(x.base10_digits()),
             Lit::Float(x) => stream.push_raw(x.base10_digits()),
 
@@ -431,7 +1374,7 @@ impl Generate for Lit {
 
             lit => {
                 stream.push_write(lit.to_token_stream());
-                stream.estimated_len += 24;
+                stream.estimated_len += DYNAMIC_LEN;
             }
         }
     }
@@ -440,7 +1383,53 @@ impl Generate for Lit {
 impl Generate for LitStr {
     #[inline]
     fn generate(&self, stream: &mut Stream) {
-        stream.push_escaped(&self.value());
+        push_text(stream, &self.value(), self.span());
+    }
+}
+
+/// Writes the text of a literal, escaped for the current context.
+fn push_text(stream: &mut Stream, value: &str, span: Span) {
+    match &stream.context {
+        Context::RawText(name) => {
+            // The closing tag may be split across literals, with or without
+            // interpolations between them, so the check runs on the static text
+            // written so far rather than on this literal alone.
+            let closing = format!("</{name}");
+            stream.raw_text.push_str(&value.to_ascii_lowercase());
+
+            if stream.raw_text.contains(&closing) {
+                let message = format!("text in `<{name}>` cannot contain `</{name}`");
+                stream.raw_text.clear();
+                return stream.push_error(syn::Error::new(span, message));
+            }
+
+            // Only the end of the text can start a closing tag.
+            let start = stream.raw_text.len().saturating_sub(closing.len() - 1);
+            let start = (start..stream.raw_text.len())
+                .find(|&i| stream.raw_text.is_char_boundary(i))
+                .unwrap_or(stream.raw_text.len());
+
+            stream.raw_text.drain(..start);
+            stream.push_raw(value);
+        }
+
+        _ => stream.push_escaped(value),
+    }
+}
+
+impl Name {
+    pub fn value(&self) -> String {
+        match self {
+            Name::Lit(x) => x.value(),
+            Name::Ident(x) => x.to_string(),
+        }
+    }
+
+    pub fn span(&self) -> Span {
+        match self {
+            Name::Lit(x) => x.span(),
+            Name::Ident(x) => x.span(),
+        }
     }
 }
 
@@ -0,0 +1,253 @@
+use std::hash::{DefaultHasher, Hash, Hasher};
+
+use proc_macro2::{Delimiter, Group as TokenGroup, Ident, TokenStream, TokenTree};
+use quote::{ToTokens, format_ident, quote};
+use syn::{Attribute, Token, Visibility, braced, parse::Parse};
+
+use crate::ast::{CrateIdent, Group, kw};
+
+pub struct Definition {
+    pub crate_ident: CrateIdent,
+    pub attrs: Vec<Attribute>,
+    pub vis: Visibility,
+    pub ident: Ident,
+    pub body: TokenStream,
+}
+
+impl Parse for Definition {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let crate_ident = input.parse()?;
+        let _comma: Token![,] = input.parse()?;
+        let attrs = input.call(Attribute::parse_outer)?;
+        let vis: Visibility = input.parse()?;
+        let ident = input.parse()?;
+        let _semi: Token![;] = input.parse()?;
+
+        Ok(Self {
+            crate_ident,
+            attrs,
+            vis,
+            ident,
+            body: input.parse()?,
+        })
+    }
+}
+
+/// Expands a layout definition into a `macro_rules!` macro that renders the
+/// layout with the blocks passed to it.
+pub fn define(definition: Definition) -> TokenStream {
+    let Definition {
+        crate_ident,
+        attrs,
+        vis,
+        ident,
+        body,
+    } = definition;
+
+    let rules = quote! {
+        ($($tt:tt)*) => {
+            #crate_ident::__asx!(#crate_ident, #![__layout(#body)] $($tt)*)
+        };
+    };
+
+    match &vis {
+        Visibility::Inherited => quote! {
+            #(#attrs)*
+            macro_rules! #ident { #rules }
+        },
+
+        // A `pub` layout is exported from the crate under a hidden name, which
+        // is unique to the layout so that layouts with the same name in
+        // different modules do not collide, and re-exported under its own name.
+        Visibility::Public(_) => {
+            let mut hasher = DefaultHasher::new();
+            body.to_string().hash(&mut hasher);
+            let export = format_ident!("__avosetta_layout_{}_{:x}", ident, hasher.finish());
+
+            quote! {
+                #[doc(hidden)]
+                #[macro_export]
+                macro_rules! #export { #rules }
+
+                #(#attrs)*
+                #vis use #export as #ident;
+            }
+        }
+
+        vis => quote! {
+            #(#attrs)*
+            macro_rules! #ident { #rules }
+
+            #vis use #ident;
+        },
+    }
+}
+
+struct Override {
+    name: Ident,
+    tokens: TokenStream,
+    used: bool,
+}
+
+impl Parse for Override {
+    #[inline]
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let tokens;
+
+        let _at: Token![@] = input.parse()?;
+        let _block: kw::block = input.parse()?;
+        let name = input.parse()?;
+        let _brace = braced!(tokens in input);
+
+        Ok(Self {
+            name,
+            tokens: tokens.parse()?,
+            used: false,
+        })
+    }
+}
+
+/// Resolves a template extending `layout`, replacing the layout's blocks with
+/// the overrides in `input`.
+///
+/// Blocks are substituted before the template is parsed, so the result is a
+/// single template and static content is merged across block boundaries.
+pub fn resolve(layout: TokenStream, input: syn::parse::ParseStream) -> syn::Result<Group> {
+    let mut overrides = Vec::<Override>::new();
+
+    while !input.is_empty() {
+        if !(input.peek(Token![@]) && input.peek2(kw::block)) {
+            return Err(input.error("expected `@block` when extending a layout"));
+        }
+
+        let x: Override = input.parse()?;
+
+        if overrides.iter().any(|y| y.name == x.name) {
+            return Err(syn::Error::new(
+                x.name.span(),
+                format!("block `{}` is overridden more than once", x.name),
+            ));
+        }
+
+        overrides.push(x);
+    }
+
+    let mut tokens = substitute(layout, &mut overrides);
+
+    // A layout may itself extend another layout, in which case the overrides
+    // it does not use are passed along to its parent.
+    if overrides.iter().any(|x| !x.used) {
+        tokens = forward(tokens, &mut overrides);
+    }
+
+    let mut errors = overrides.iter().filter(|x| !x.used).map(|x| {
+        syn::Error::new(
+            x.name.span(),
+            format!("no block named `{}` in this layout", x.name),
+        )
+    });
+
+    if let Some(mut err) = errors.next() {
+        err.extend(errors);
+        return Err(err);
+    }
+
+    syn::parse2(tokens)
+}
+
+/// Returns the block named by the tokens at the start of `trees`, if they are
+/// an `@block name { ... }` node.
+fn block(trees: &[TokenTree]) -> Option<(&Ident, &TokenGroup)> {
+    match trees {
+        [
+            TokenTree::Punct(at),
+            TokenTree::Ident(block),
+            TokenTree::Ident(name),
+            TokenTree::Group(group),
+            ..,
+        ] if at.as_char() == '@' && block == "block" && group.delimiter() == Delimiter::Brace => {
+            Some((name, group))
+        }
+
+        _ => None,
+    }
+}
+
+fn substitute(tokens: TokenStream, overrides: &mut [Override]) -> TokenStream {
+    let trees = tokens.into_iter().collect::<Vec<_>>();
+    let mut out = TokenStream::new();
+    let mut i = 0;
+
+    while i < trees.len() {
+        if let Some((name, group)) = block(&trees[i..]) {
+            let inner = match overrides.iter_mut().find(|x| x.name == *name) {
+                Some(x) => {
+                    x.used = true;
+                    x.tokens.clone()
+                }
+
+                None => substitute(group.stream(), overrides),
+            };
+
+            out.extend(trees[i..i + 3].iter().cloned());
+            respan(group, inner).to_tokens(&mut out);
+
+            i += 4;
+            continue;
+        }
+
+        match &trees[i] {
+            TokenTree::Group(group) => {
+                respan(group, substitute(group.stream(), overrides)).to_tokens(&mut out)
+            }
+
+            tree => tree.to_tokens(&mut out),
+        }
+
+        i += 1;
+    }
+
+    out
+}
+
+/// Appends the unused overrides to the body of the layout's `@extends` node.
+fn forward(tokens: TokenStream, overrides: &mut [Override]) -> TokenStream {
+    let mut trees = tokens.into_iter().collect::<Vec<_>>();
+
+    let extends = trees.windows(2).position(|x| {
+        matches!(x, [TokenTree::Punct(at), TokenTree::Ident(extends)]
+            if at.as_char() == '@' && extends == "extends")
+    });
+
+    let body = extends.and_then(|i| {
+        trees[i..].iter().position(
+            |x| matches!(x, TokenTree::Group(group) if group.delimiter() == Delimiter::Brace),
+        )
+    });
+
+    let (Some(extends), Some(body)) = (extends, body) else {
+        return trees.into_iter().collect();
+    };
+
+    let TokenTree::Group(group) = &trees[extends + body] else {
+        unreachable!();
+    };
+
+    let mut inner = group.stream();
+
+    for x in overrides.iter_mut().filter(|x| !x.used) {
+        let Override { name, tokens, .. } = &*x;
+        inner.extend(quote! { @block #name { #tokens } });
+        x.used = true;
+    }
+
+    trees[extends + body] = TokenTree::Group(respan(group, inner));
+    trees.into_iter().collect()
+}
+
+#[inline]
+fn respan(group: &TokenGroup, stream: TokenStream) -> TokenGroup {
+    let mut out = TokenGroup::new(group.delimiter(), stream);
+    out.set_span(group.span());
+    out
+}
@@ -2,29 +2,42 @@ use proc_macro2::TokenStream;
 use quote::quote;
 
 use crate::{
-    ast::{Group, Input},
+    ast::{Input, Template},
     generate::{Generate, Stream},
 };
 
+mod a11y;
 mod ast;
 mod completion;
+mod derive;
+mod diagnostic;
 mod generate;
+mod layout;
+mod names;
+mod nesting;
 mod parse;
+mod template;
 
 #[proc_macro]
 pub fn asx(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
     crate::completion::clear();
+    crate::diagnostic::clear();
 
     let input = syn::parse_macro_input!(item as Input);
 
     let mut tokens = TokenStream::new();
 
-    match syn::parse2::<Group>(input.tokens) {
-        Ok(group) => {
+    match syn::parse2::<Template>(input.tokens) {
+        Ok(template) => {
             tokens.extend(crate::completion::to_token_stream(&input.crate_ident));
 
-            let mut stream = Stream::new(input.crate_ident);
-            group.generate(&mut stream);
+            if let Some(err) = check(&template) {
+                tokens.extend(err.into_compile_error());
+            }
+
+            let mut stream = Stream::new(input.crate_ident, template.options);
+            template.group.generate(&mut stream);
+            tokens.extend(crate::diagnostic::to_token_stream());
             tokens.extend(stream.into_token_stream());
         }
 
@@ -36,3 +49,52 @@ pub fn asx(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
 
     quote! {{ #tokens }}.into()
 }
+
+/// Runs the opt-in checks that look at the whole template before it is
+/// generated, returning the errors they found.
+fn check(template: &Template) -> Option<syn::Error> {
+    let mut errors = Vec::new();
+
+    if template.options.check_nesting {
+        errors.extend(crate::nesting::check(&template.group));
+    }
+
+    if let Some(lint) = template.options.check_a11y {
+        errors.extend(crate::a11y::check(&template.group, lint));
+    }
+
+    errors.into_iter().reduce(|mut x, y| {
+        x.combine(y);
+        x
+    })
+}
+
+#[proc_macro]
+pub fn layout(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let definition = syn::parse_macro_input!(item as crate::layout::Definition);
+    crate::layout::define(definition).into()
+}
+
+#[proc_macro_attribute]
+pub fn template(
+    attr: proc_macro::TokenStream,
+    item: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    let args = syn::parse_macro_input!(attr as crate::template::Args);
+    let item = syn::parse_macro_input!(item as syn::ItemFn);
+
+    match crate::template::expand(args, item) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.into_compile_error().into(),
+    }
+}
+
+#[proc_macro_derive(Html, attributes(html))]
+pub fn derive_html(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = syn::parse_macro_input!(item as syn::DeriveInput);
+
+    match crate::derive::expand(input) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.into_compile_error().into(),
+    }
+}
@@ -0,0 +1,102 @@
+use avosetta_names::{
+    EXTRA_ATTRS, VOID_ELEMENTS, attrs::NAMES as ATTRS, elements::NAMES as ELEMENTS,
+};
+use proc_macro2::Span;
+
+/// Returns `true` if `name` is a known HTML element.
+pub fn is_element(name: &str) -> bool {
+    ELEMENTS.iter().any(|x| x.eq_ignore_ascii_case(name))
+}
+
+/// Returns `true` if `name` is an HTML void element.
+pub fn is_void(name: &str) -> bool {
+    VOID_ELEMENTS.iter().any(|x| x.eq_ignore_ascii_case(name))
+}
+
+/// Warns if `name` is not a known HTML element.
+///
+/// Custom element names, which contain a hyphen, are always allowed.
+pub fn check_element(name: &str, span: Span) {
+    if name.contains('-') {
+        return;
+    }
+
+    check(name, span, "element", ELEMENTS.iter());
+}
+
+/// Warns if `name` is not a known HTML attribute.
+///
+/// `data-*` and `aria-*` attributes, and namespaced attributes such as
+/// `xml:lang`, are always allowed.
+pub fn check_attr(name: &str, span: Span) {
+    let lower = name.to_ascii_lowercase();
+
+    if lower.starts_with("data-") || lower.starts_with("aria-") || lower.contains(':') {
+        return;
+    }
+
+    check(name, span, "attribute", ATTRS.iter().chain(EXTRA_ATTRS));
+}
+
+fn check<'a, I>(name: &str, span: Span, kind: &str, known: I)
+where
+    I: Iterator<Item = &'a &'a str> + Clone,
+{
+    let lower = name.to_ascii_lowercase();
+
+    if known.clone().any(|x| *x == lower) {
+        return;
+    }
+
+    let message = match suggest(&lower, known.copied()) {
+        Some(x) => format!("unknown {kind} `{name}`; did you mean `{x}`?"),
+        None => format!("unknown {kind} `{name}`"),
+    };
+
+    crate::diagnostic::warn(span, message);
+}
+
+/// Returns the name in `known` that is closest to the lowercase `name`, if any
+/// is close enough to be a likely typo.
+pub fn suggest<'a, I>(name: &str, known: I) -> Option<&'a str>
+where
+    I: Iterator<Item = &'a str>,
+{
+    // Only suggest names that are close relative to the length of the name, so
+    // that short names are not matched to unrelated ones.
+    known
+        .map(|x| (distance(name, x), x))
+        .filter(|(distance, _)| *distance <= (name.len() / 3).max(1))
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, x)| x)
+}
+
+/// Returns the number of single-character insertions, deletions,
+/// substitutions, and adjacent swaps needed to turn `a` into `b`.
+fn distance(a: &str, b: &str) -> usize {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+
+    let mut rows = vec![(0..=b.len()).collect::<Vec<_>>(); a.len() + 1];
+
+    for (i, row) in rows.iter_mut().enumerate() {
+        row[0] = i;
+    }
+
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+
+            let mut x = (rows[i - 1][j] + 1)
+                .min(rows[i][j - 1] + 1)
+                .min(rows[i - 1][j - 1] + cost);
+
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                x = x.min(rows[i - 2][j - 2] + 1);
+            }
+
+            rows[i][j] = x;
+        }
+    }
+
+    rows[a.len()][b.len()]
+}
@@ -0,0 +1,330 @@
+use proc_macro2::Span;
+use syn::LitStr;
+
+use crate::ast::{Element, Group, InterpArmExpr, InterpValue, Name, Node};
+
+/// Elements that close an open `p` element when they start.
+const CLOSES_P: &[&str] = &[
+    "address",
+    "article",
+    "aside",
+    "blockquote",
+    "details",
+    "dialog",
+    "div",
+    "dl",
+    "fieldset",
+    "figcaption",
+    "figure",
+    "footer",
+    "form",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "header",
+    "hgroup",
+    "hr",
+    "main",
+    "menu",
+    "nav",
+    "ol",
+    "p",
+    "pre",
+    "search",
+    "section",
+    "table",
+    "ul",
+];
+
+/// Elements that stop an open `p` element from being closed by its
+/// descendants.
+const P_SCOPE: &[&str] = &["button", "caption", "object", "table", "td", "th"];
+
+/// Interactive elements, which cannot be inside `a` or `button`.
+const INTERACTIVE: &[&str] = &[
+    "a", "button", "details", "embed", "iframe", "label", "select", "textarea",
+];
+
+const HEADINGS: &[&str] = &["h1", "h2", "h3", "h4", "h5", "h6"];
+
+/// Elements and the descendants they cannot contain at any depth.
+const FORBIDDEN_DESCENDANTS: &[(&str, &[&str])] = &[
+    ("a", INTERACTIVE),
+    ("button", INTERACTIVE),
+    ("form", &["form"]),
+    ("label", &["label"]),
+    ("h1", HEADINGS),
+    ("h2", HEADINGS),
+    ("h3", HEADINGS),
+    ("h4", HEADINGS),
+    ("h5", HEADINGS),
+    ("h6", HEADINGS),
+];
+
+/// Elements and the parents they must be a direct child of.
+const PARENTS: &[(&str, &[&str])] = &[
+    ("li", &["ul", "ol", "menu"]),
+    ("dt", &["dl", "div"]),
+    ("dd", &["dl", "div"]),
+    ("caption", &["table"]),
+    ("colgroup", &["table"]),
+    ("thead", &["table"]),
+    ("tbody", &["table"]),
+    ("tfoot", &["table"]),
+    ("tr", &["table", "thead", "tbody", "tfoot"]),
+    ("td", &["tr"]),
+    ("th", &["tr"]),
+    ("col", &["colgroup"]),
+    ("optgroup", &["select"]),
+    ("option", &["select", "datalist", "optgroup"]),
+    ("summary", &["details"]),
+    ("legend", &["fieldset"]),
+    ("figcaption", &["figure"]),
+    ("source", &["audio", "video", "picture"]),
+    ("track", &["audio", "video"]),
+];
+
+/// Elements and the only elements they can have as direct children, besides
+/// `script` and `template`. None of them can contain text.
+const CHILDREN: &[(&str, &[&str])] = &[
+    ("ul", &["li"]),
+    ("ol", &["li"]),
+    ("menu", &["li"]),
+    ("dl", &["dt", "dd", "div"]),
+    (
+        "table",
+        &["caption", "colgroup", "thead", "tbody", "tfoot", "tr"],
+    ),
+    ("thead", &["tr"]),
+    ("tbody", &["tr"]),
+    ("tfoot", &["tr"]),
+    ("tr", &["td", "th"]),
+    ("colgroup", &["col"]),
+];
+
+/// Checks the nesting of the elements in `group` against the HTML content
+/// model, returning every violation that is known without running the
+/// template.
+///
+/// Control flow is transparent, so `ul { @for x in xs { li { } } }` is checked
+/// as if each `li` were written inside the `ul`. Component children and the
+/// contents of `template` elements are checked as separate trees, since where
+/// they end up is not known here.
+pub fn check(group: &Group) -> Option<syn::Error> {
+    let mut checker = Checker::default();
+    checker.group(group);
+    checker.errors
+}
+
+#[derive(Default)]
+struct Checker {
+    ancestors: Vec<String>,
+    errors: Option<syn::Error>,
+}
+
+impl Checker {
+    fn group(&mut self, group: &Group) {
+        for node in &group.0 {
+            match node {
+                Node::Element(x) => self.element(x),
+                Node::Interp(x) => self.interp(&x.value),
+                Node::Literal(x) => self.text(x),
+            }
+        }
+    }
+
+    fn separate(&mut self, group: &Group) {
+        let ancestors = std::mem::take(&mut self.ancestors);
+        self.group(group);
+        self.ancestors = ancestors;
+    }
+
+    fn element(&mut self, element: &Element) {
+        let (name, inner) = match element {
+            Element::Normal(x) => (&x.name, Some(&x.inner)),
+            Element::Void(x) => (&x.name, None),
+
+            Element::Component(x) => {
+                if let Some(children) = &x.children {
+                    for slot in &children.slots {
+                        self.separate(&slot.group);
+                    }
+
+                    self.separate(&children.group);
+                }
+
+                return;
+            }
+        };
+
+        let value = name.value().to_ascii_lowercase();
+        self.check(&value, name);
+
+        let Some(inner) = inner else {
+            return;
+        };
+
+        match value.as_str() {
+            // Foreign content and raw text follow rules of their own.
+            "svg" | "math" | "script" | "style" | "textarea" | "title" => {}
+
+            "template" => self.separate(inner),
+
+            _ => {
+                self.ancestors.push(value);
+                self.group(inner);
+                self.ancestors.pop();
+            }
+        }
+    }
+
+    fn interp(&mut self, value: &InterpValue) {
+        match value {
+            InterpValue::Match(x) => {
+                for arm in &x.arms {
+                    match &arm.expr {
+                        InterpArmExpr::Group(x) => self.group(&x.group),
+                        InterpArmExpr::Literal(x) => self.text(x),
+                    }
+                }
+            }
+
+            InterpValue::If(x) => {
+                self.group(&x.then_branch);
+
+                for x in &x.else_if {
+                    self.group(&x.group);
+                }
+
+                if let Some(x) = &x.else_branch {
+                    self.group(&x.group);
+                }
+            }
+
+            InterpValue::For(x) => self.group(&x.body),
+
+            InterpValue::Defer(x) => {
+                self.group(&x.body);
+
+                if let Some(x) = &x.fallback {
+                    self.group(&x.group);
+                }
+            }
+
+            InterpValue::Block(x) => self.group(&x.group),
+
+            InterpValue::Await(_)
+            | InterpValue::Extends(_)
+            | InterpValue::Expr(_)
+            | InterpValue::Stmt(_) => {}
+        }
+    }
+
+    fn check(&mut self, value: &str, name: &Name) {
+        // Custom elements can appear anywhere that flow content can.
+        if value.contains('-') {
+            return;
+        }
+
+        let parent = self.ancestors.last().map(String::as_str);
+
+        if let Some(parent) = parent {
+            if let Some((_, parents)) = lookup(PARENTS, value)
+                && !parents.contains(&parent)
+                && !parent.contains('-')
+            {
+                let message = format!(
+                    "`{value}` must be a direct child of {}, not `{parent}`",
+                    list(parents)
+                );
+
+                return self.error(name.span(), message);
+            }
+
+            if let Some((_, children)) = lookup(CHILDREN, parent)
+                && !children.contains(&value)
+                && !matches!(value, "script" | "template")
+            {
+                let message = format!("`{value}` cannot be a direct child of `{parent}`");
+                return self.error(name.span(), message);
+            }
+        }
+
+        for ancestor in self.ancestors.iter().rev() {
+            if P_SCOPE.contains(&ancestor.as_str()) {
+                break;
+            }
+
+            if ancestor == "p" && CLOSES_P.contains(&value) {
+                let message = format!(
+                    "`{value}` cannot be inside `p`; browsers would close the `p` before it"
+                );
+
+                return self.error(name.span(), message);
+            }
+        }
+
+        for ancestor in &self.ancestors {
+            let Some((_, forbidden)) = lookup(FORBIDDEN_DESCENDANTS, ancestor) else {
+                continue;
+            };
+
+            if forbidden.contains(&value) {
+                let message = if ancestor == value {
+                    format!("`{value}` cannot be inside another `{ancestor}`")
+                } else {
+                    format!("`{value}` cannot be inside `{ancestor}`")
+                };
+
+                return self.error(name.span(), message);
+            }
+        }
+    }
+
+    fn text(&mut self, text: &LitStr) {
+        let Some(parent) = self.ancestors.last() else {
+            return;
+        };
+
+        if lookup(CHILDREN, parent).is_some() && !text.value().trim().is_empty() {
+            let message = format!("text cannot be a direct child of `{parent}`");
+            self.error(text.span(), message);
+        }
+    }
+
+    fn error(&mut self, span: Span, message: String) {
+        let err = syn::Error::new(span, message);
+
+        match &mut self.errors {
+            Some(x) => x.combine(err),
+            None => self.errors = Some(err),
+        }
+    }
+}
+
+fn lookup<'a>(
+    table: &'a [(&'static str, &'static [&'static str])],
+    name: &str,
+) -> Option<&'a (&'static str, &'static [&'static str])> {
+    table.iter().find(|(x, _)| *x == name)
+}
+
+/// Formats `names` as "`a`, `b` or `c`".
+fn list(names: &[&str]) -> String {
+    let mut out = String::new();
+
+    for (i, name) in names.iter().enumerate() {
+        if i > 0 {
+            out.push_str(if i + 1 == names.len() { " or " } else { ", " });
+        }
+
+        out.push('`');
+        out.push_str(name);
+        out.push('`');
+    }
+
+    out
+}
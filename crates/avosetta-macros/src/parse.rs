@@ -1,9 +1,12 @@
-use proc_macro2::Span;
+use proc_macro2::{Delimiter, Span, TokenStream, TokenTree};
+use quote::ToTokens;
 use syn::{
-    Expr, Ident, Item, LitStr, Pat, Stmt, Token, braced, bracketed,
-    parse::Parse,
+    Expr, Ident, Item, LitInt, LitStr, Pat, Path, Stmt, Token, braced, bracketed,
+    ext::IdentExt,
+    parenthesized,
+    parse::{Parse, ParseStream},
     punctuated::Punctuated,
-    token::{Brace, Bracket},
+    token::{Brace, Bracket, Paren},
 };
 
 use crate::ast::*;
@@ -24,12 +27,109 @@ impl Parse for CrateIdent {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
         if input.peek(Token![crate]) {
             Ok(Self::Crate(input.parse()?))
+        } else if input.peek(Token![::]) || input.peek2(Token![::]) {
+            Ok(Self::Path(input.call(Path::parse_mod_style)?))
         } else {
             Ok(Self::Ident(input.parse()?))
         }
     }
 }
 
+impl Parse for Template {
+    #[inline]
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let options: Options = input.parse()?;
+
+        let group = match &options.layout {
+            Some(layout) => crate::layout::resolve(layout.clone(), input)?,
+            None => input.parse()?,
+        };
+
+        Ok(Self { options, group })
+    }
+}
+
+impl Parse for Options {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let mut options = Self::default();
+        let mut send = None;
+
+        while input.peek(Token![#]) && input.peek2(Token![!]) {
+            let inner;
+
+            let pound: Token![#] = input.parse()?;
+            let bang: Token![!] = input.parse()?;
+            let bracket = bracketed!(inner in input);
+
+            let raw: TokenStream = inner.fork().parse()?;
+            let ident: Ident = inner.parse()?;
+
+            match ident.to_string().as_str() {
+                "reusable" => options.reusable = true,
+                "send" => {
+                    options.send = true;
+                    send = Some(ident.span());
+                }
+                "check_urls" => options.check_urls = true,
+                "check_names" => options.check_names = true,
+                "check_nesting" => options.check_nesting = true,
+
+                "check_a11y" => {
+                    let mut lint = Lint::Warn;
+
+                    if inner.peek(Paren) {
+                        let mode;
+                        let _paren = parenthesized!(mode in inner);
+                        let ident: Ident = mode.parse()?;
+
+                        if ident != "strict" {
+                            return Err(syn::Error::new(ident.span(), "expected `strict`"));
+                        }
+
+                        lint = Lint::Deny;
+                    }
+
+                    options.check_a11y = Some(lint);
+                }
+
+                "__nested" => options.nested = true,
+
+                "__layout" => {
+                    let layout;
+                    let _paren = parenthesized!(layout in inner);
+                    options.layout = Some(layout.parse()?);
+                }
+
+                _ => {
+                    return Err(syn::Error::new(
+                        ident.span(),
+                        format!("unknown template option `{ident}`"),
+                    ));
+                }
+            }
+
+            if !inner.is_empty() {
+                return Err(inner.error("expected `]`"));
+            }
+
+            if ident != "__layout" {
+                pound.to_tokens(&mut options.tokens);
+                bang.to_tokens(&mut options.tokens);
+                bracket.surround(&mut options.tokens, |tokens| tokens.extend(raw));
+            }
+        }
+
+        if let (true, Some(span)) = (options.reusable, send) {
+            return Err(syn::Error::new(
+                span,
+                "`send` templates are asynchronous and cannot be `reusable`",
+            ));
+        }
+
+        Ok(options)
+    }
+}
+
 impl Parse for Group {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
         let mut nodes = Vec::new();
@@ -47,22 +147,29 @@ impl Parse for Node {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
         let lookahead = input.lookahead1();
 
-        if lookahead.peek(LitStr) || lookahead.peek(Ident) {
+        if lookahead.peek(LitStr) || lookahead.peek(Ident::peek_any) {
             if let Ok(ident) = input.fork().parse() {
                 crate::completion::push_element(ident);
             }
 
-            if input.peek2(Brace)
-                || (input.peek2(Bracket) && input.peek3(Brace))
-                || input.peek2(Token![;])
-                || (input.peek2(Bracket) && input.peek3(Token![;]))
+            let fork = input.fork();
+
+            if fork.parse::<Name>().is_ok()
+                && (fork.peek(Brace)
+                    || (fork.peek(Bracket) && fork.peek2(Brace))
+                    || fork.peek(Token![;])
+                    || (fork.peek(Bracket) && fork.peek2(Token![;])))
             {
                 Ok(Self::Element(input.parse()?))
             } else {
                 Ok(Self::Literal(input.parse()?))
             }
         } else if lookahead.peek(Token![@]) {
-            Ok(Self::Interp(input.parse()?))
+            if Component::peek(input) {
+                Ok(Self::Element(Element::Component(input.parse()?)))
+            } else {
+                Ok(Self::Interp(input.parse()?))
+            }
         } else {
             Err(lookahead.error())
         }
@@ -88,6 +195,16 @@ impl Parse for InterpValue {
             Ok(Self::Match(input.parse()?))
         } else if input.peek(Token![for]) {
             Ok(Self::For(input.parse()?))
+        } else if input.peek(Token![await]) {
+            Ok(Self::Await(input.parse()?))
+        } else if input.peek(kw::defer) && (input.peek2(Ident) || input.peek2(Token![&])) {
+            Ok(Self::Defer(input.parse()?))
+        } else if input.peek(kw::block) && input.peek2(Ident) && input.peek3(Brace) {
+            Ok(Self::Block(input.parse()?))
+        } else if input.peek(kw::extends)
+            && (input.peek2(Ident) || input.peek2(Token![::]) || input.peek2(Token![crate]))
+        {
+            Ok(Self::Extends(input.parse()?))
         } else if input.peek(Token![let]) {
             Ok(Self::Stmt(input.parse()?))
         } else {
@@ -102,6 +219,63 @@ impl Parse for InterpValue {
     }
 }
 
+impl Parse for InterpAwait {
+    #[inline]
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        Ok(Self {
+            await_token: input.parse()?,
+            expr: input.parse()?,
+        })
+    }
+}
+
+impl Parse for InterpDefer {
+    #[inline]
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let body;
+
+        Ok(Self {
+            defer_token: input.parse()?,
+            suspense: input.call(Expr::parse_without_eager_brace)?,
+            _brace: braced!(body in input),
+            body: body.parse()?,
+            fallback: if input.peek(Token![else]) {
+                Some(input.parse()?)
+            } else {
+                None
+            },
+        })
+    }
+}
+
+impl Parse for InterpBlock {
+    #[inline]
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let group;
+
+        Ok(Self {
+            block_token: input.parse()?,
+            _name: input.parse()?,
+            _brace: braced!(group in input),
+            group: group.parse()?,
+        })
+    }
+}
+
+impl Parse for InterpExtends {
+    #[inline]
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let body;
+
+        Ok(Self {
+            _extends_token: input.parse()?,
+            path: input.call(Path::parse_mod_style)?,
+            _brace: braced!(body in input),
+            body: body.parse()?,
+        })
+    }
+}
+
 impl Parse for InterpIf {
     #[inline]
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
@@ -238,7 +412,10 @@ impl Parse for InterpArmGroup {
 impl Parse for Element {
     #[inline]
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
-        if input.peek2(Token![;]) || (input.peek2(Bracket) && input.peek3(Token![;])) {
+        let fork = input.fork();
+        fork.parse::<Name>()?;
+
+        if fork.peek(Token![;]) || (fork.peek(Bracket) && fork.peek2(Token![;])) {
             Ok(Self::Void(input.parse()?))
         } else {
             Ok(Self::Normal(input.parse()?))
@@ -246,6 +423,111 @@ impl Parse for Element {
     }
 }
 
+impl Component {
+    /// Returns `true` if the input starts with a component invocation, a path
+    /// followed by either named props, as in `@Card(title = x)`, or empty
+    /// parentheses and a children block, as in `@Card() { ... }`.
+    ///
+    /// Anything else is parsed as an ordinary `@` expression, so existing
+    /// function calls such as `@render(x)` keep their meaning.
+    fn peek(input: ParseStream) -> bool {
+        let fork = input.fork();
+
+        if fork.parse::<Token![@]>().is_err() || fork.call(Path::parse_mod_style).is_err() {
+            return false;
+        }
+
+        let Ok(TokenTree::Group(group)) = fork.parse::<TokenTree>() else {
+            return false;
+        };
+
+        if group.delimiter() != Delimiter::Parenthesis {
+            return false;
+        }
+
+        let mut tokens = group.stream().into_iter();
+
+        match (tokens.next(), tokens.next()) {
+            (None, _) => fork.peek(Brace),
+
+            (Some(TokenTree::Ident(_)), Some(TokenTree::Punct(punct))) => {
+                punct.as_char() == '=' && punct.spacing() == proc_macro2::Spacing::Alone
+            }
+
+            _ => false,
+        }
+    }
+}
+
+impl Parse for Component {
+    #[inline]
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let props;
+
+        Ok(Self {
+            _at_token: input.parse()?,
+            path: input.call(Path::parse_mod_style)?,
+            _paren: parenthesized!(props in input),
+            props: props.parse_terminated(Prop::parse, Token![,])?,
+            children: if input.peek(Brace) {
+                Some(input.parse()?)
+            } else {
+                None
+            },
+        })
+    }
+}
+
+impl Parse for Prop {
+    #[inline]
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        Ok(Self {
+            name: input.parse()?,
+            _eq_token: input.parse()?,
+            expr: input.parse()?,
+        })
+    }
+}
+
+impl Parse for Children {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let inner;
+        let _brace = braced!(inner in input);
+
+        let mut slots = Vec::new();
+        let mut nodes = Vec::new();
+
+        while !inner.is_empty() {
+            if inner.peek(Token![@]) && inner.peek2(kw::slot) && inner.peek3(Ident) {
+                slots.push(inner.parse()?);
+            } else {
+                nodes.push(inner.parse()?);
+            }
+        }
+
+        Ok(Self {
+            _brace,
+            slots: slots.into_boxed_slice(),
+            group: Group(nodes.into_boxed_slice()),
+        })
+    }
+}
+
+impl Parse for SlotFill {
+    #[inline]
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let group;
+
+        Ok(Self {
+            _at_token: input.parse()?,
+            _slot_token: input.parse()?,
+            name: input.parse()?,
+            _brace: braced!(group in input),
+            group: group.parse()?,
+        })
+    }
+}
+
 impl Parse for Normal {
     #[inline]
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
@@ -256,7 +538,7 @@ impl Parse for Normal {
 
             attrs: input.parse()?,
 
-            _brace: braced!(inner in input),
+            brace: braced!(inner in input),
 
             inner: inner.parse::<Group>()?,
         })
@@ -269,7 +551,7 @@ impl Parse for Void {
         Ok(Self {
             name: input.parse()?,
             attrs: input.parse()?,
-            _semi_token: input.parse()?,
+            semi_token: input.parse()?,
         })
     }
 }
@@ -282,7 +564,7 @@ impl Parse for Attrs {
 
             Ok(Self {
                 _bracket: bracketed!(inner in input),
-                inner: inner.parse_terminated(Attr::parse, Token![,])?,
+                inner: inner.parse_terminated(AttrItem::parse, Token![,])?,
             })
         } else {
             Ok(Self {
@@ -293,6 +575,57 @@ impl Parse for Attrs {
     }
 }
 
+impl Parse for AttrItem {
+    #[inline]
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        if input.peek(Token![..]) {
+            return Ok(Self::Spread(Spread {
+                _dot2_token: input.parse()?,
+                expr: input.parse()?,
+            }));
+        }
+
+        // `class:` and `style:` start toggles and style maps, so they are
+        // checked before a name that could include the `:`.
+        let fork = input.fork();
+        let name = Name::parse_joined(&fork, false).map(|x| x.value());
+        let name = name.unwrap_or_default();
+        let is_prefixed = fork.peek(Token![:]) && !fork.peek(Token![::]);
+
+        if name == "style" && is_prefixed && fork.peek2(Brace) {
+            let inner;
+
+            Ok(Self::Style(StyleMap {
+                style: Name::parse_joined(input, false)?,
+                _colon_token: input.parse()?,
+                _brace: braced!(inner in input),
+                decls: inner.parse_terminated(StyleDecl::parse, Token![,])?,
+            }))
+        } else if name == "class" && is_prefixed {
+            Ok(Self::Toggle(Toggle {
+                class: Name::parse_joined(input, false)?,
+                _colon_token: input.parse()?,
+                name: input.parse()?,
+                _eq_token: input.parse()?,
+                cond: input.parse()?,
+            }))
+        } else {
+            Ok(Self::Attr(input.parse()?))
+        }
+    }
+}
+
+impl Parse for StyleDecl {
+    #[inline]
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        Ok(Self {
+            property: Name::parse_joined(input, false)?,
+            _colon_token: input.parse()?,
+            value: input.parse()?,
+        })
+    }
+}
+
 impl Parse for Attr {
     #[inline]
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
@@ -314,26 +647,62 @@ impl Parse for Attr {
 impl Parse for Name {
     #[inline]
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
-        let lookahead = input.lookahead1();
+        Self::parse_joined(input, true)
+    }
+}
 
-        if lookahead.peek(LitStr) {
-            Ok(Self::Lit(input.parse()?))
-        } else if lookahead.peek(Ident) {
-            Ok(Self::Ident(input.parse()?))
-        } else if lookahead.peek(Token![as]) {
-            let _as: Token![as] = input.parse()?;
-            Ok(Self::Lit(LitStr::new("as", _as.span)))
-        } else if lookahead.peek(Token![type]) {
-            let _type: Token![type] = input.parse()?;
-            Ok(Self::Lit(LitStr::new("type", _type.span)))
-        } else if lookahead.peek(Token![for]) {
-            let _for: Token![for] = input.parse()?;
-            Ok(Self::Lit(LitStr::new("for", _for.span)))
-        } else if lookahead.peek(Token![loop]) {
-            let _loop: Token![loop] = input.parse()?;
-            Ok(Self::Lit(LitStr::new("loop", _loop.span)))
+impl Name {
+    /// Parses a name made of identifiers and keywords joined by `-`, and by
+    /// `:` and `.` if `all` is `true`, as in `hx-get`, `xlink:href` or
+    /// `x-on:click.prevent`. A segment after the first may also be a number,
+    /// as in `h-100`.
+    ///
+    /// CSS properties only use `-`, since `color: red` must not be read as one
+    /// name.
+    pub fn parse_joined(input: ParseStream, all: bool) -> syn::Result<Self> {
+        if input.peek(LitStr) {
+            return Ok(Self::Lit(input.parse()?));
+        }
+
+        if !input.peek(Ident::peek_any) {
+            return Err(input.error("expected string literal or identifier"));
+        }
+
+        let first = Ident::parse_any(input)?;
+        let span = first.span();
+        let mut value = first.unraw().to_string();
+        let mut joined = false;
+
+        loop {
+            let separator = if input.peek(Token![-]) {
+                '-'
+            } else if all && input.peek(Token![:]) && !input.peek(Token![::]) {
+                ':'
+            } else if all && input.peek(Token![.]) && !input.peek(Token![..]) {
+                '.'
+            } else {
+                break;
+            };
+
+            let segment = if input.peek2(Ident::peek_any) {
+                let _ = input.parse::<TokenTree>()?;
+                Ident::parse_any(input)?.unraw().to_string()
+            } else if input.peek2(LitInt) {
+                let _ = input.parse::<TokenTree>()?;
+                input.parse::<LitInt>()?.to_string()
+            } else {
+                break;
+            };
+
+            value.push(separator);
+            value.push_str(&segment);
+            joined = true;
+        }
+
+        if joined || first != value {
+            Ok(Self::Lit(LitStr::new(&value, span)))
         } else {
-            Err(lookahead.error())
+            Ok(Self::Ident(first))
         }
     }
 }
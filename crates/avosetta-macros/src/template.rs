@@ -0,0 +1,573 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{ToTokens, format_ident, quote, quote_spanned};
+use syn::{
+    Attribute, FnArg, GenericParam, Generics, Ident, ItemFn, Meta, Pat, PatIdent, Path,
+    PathArguments, ReturnType, Token, TraitBound, Type, TypeParam, TypeParamBound, TypePath,
+    Visibility, WherePredicate, parse::Parse, parse_quote, punctuated::Punctuated,
+};
+
+pub struct Args {
+    pub crate_path: Path,
+    pub name: Option<Ident>,
+}
+
+impl Parse for Args {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let mut args = Self {
+            crate_path: parse_quote!(::avosetta),
+            name: None,
+        };
+
+        for arg in Punctuated::<Arg, Token![,]>::parse_terminated(input)? {
+            match arg {
+                Arg::Crate(x) => args.crate_path = x,
+                Arg::Name(x) => args.name = Some(x),
+            }
+        }
+
+        Ok(args)
+    }
+}
+
+enum Arg {
+    Crate(Path),
+    Name(Ident),
+}
+
+impl Parse for Arg {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let lookahead = input.lookahead1();
+
+        if lookahead.peek(Token![crate]) {
+            let _crate: Token![crate] = input.parse()?;
+            let _eq: Token![=] = input.parse()?;
+            Ok(Self::Crate(input.call(Path::parse_mod_style)?))
+        } else if lookahead.peek(Ident) {
+            let ident: Ident = input.parse()?;
+
+            if ident != "name" {
+                return Err(syn::Error::new(
+                    ident.span(),
+                    format!("unknown template argument `{ident}`"),
+                ));
+            }
+
+            let _eq: Token![=] = input.parse()?;
+            Ok(Self::Name(input.parse()?))
+        } else {
+            Err(lookahead.error())
+        }
+    }
+}
+
+pub fn expand(args: Args, item: ItemFn) -> syn::Result<TokenStream> {
+    let Args { crate_path, name } = args;
+
+    let ItemFn {
+        attrs,
+        vis,
+        sig,
+        block,
+    } = item;
+
+    if let Some(x) = &sig.asyncness {
+        return Err(syn::Error::new_spanned(
+            x,
+            "`#[template]` cannot be used on an async function",
+        ));
+    }
+
+    if let Some(x) = &sig.abi {
+        return Err(syn::Error::new_spanned(
+            x,
+            "`#[template]` cannot be used on an `extern` function",
+        ));
+    }
+
+    if let Some(x) = &sig.variadic {
+        return Err(syn::Error::new_spanned(
+            x,
+            "`#[template]` cannot be used on a variadic function",
+        ));
+    }
+
+    check_output(&sig.output, sig.ident.span())?;
+
+    let name = name.unwrap_or_else(|| {
+        Ident::new(
+            &to_upper_camel_case(&sig.ident.to_string()),
+            sig.ident.span(),
+        )
+    });
+
+    let mut generics = sig.generics.clone();
+    let mut fields = Vec::new();
+    let mut field_pats = Vec::new();
+
+    for input in &sig.inputs {
+        match input {
+            FnArg::Typed(x) => match &*x.pat {
+                Pat::Ident(PatIdent {
+                    ident,
+                    subpat: None,
+                    ..
+                }) => {
+                    let (slot, optional) = parse_slot_attrs(&x.attrs)?;
+
+                    let ty = match &*x.ty {
+                        Type::ImplTrait(ty) => {
+                            let param =
+                                Ident::new(&to_upper_camel_case(&ident.to_string()), ident.span());
+
+                            generics.params.push(GenericParam::Type(TypeParam {
+                                attrs: Vec::new(),
+                                ident: param.clone(),
+                                colon_token: Some(Default::default()),
+                                bounds: ty.bounds.clone(),
+                                eq_token: None,
+                                default: None,
+                            }));
+
+                            parse_quote!(#param)
+                        }
+
+                        ty => ty.clone(),
+                    };
+
+                    if optional && !is_type_param(&ty, &generics) {
+                        return Err(syn::Error::new_spanned(
+                            &x.ty,
+                            "optional slots must have a generic type, such as `impl Html`",
+                        ));
+                    }
+
+                    field_pats.push(&x.pat);
+                    fields.push(Field {
+                        ident,
+                        vis: &vis,
+                        ty,
+                        slot,
+                        optional,
+                    });
+                }
+
+                pat => {
+                    return Err(syn::Error::new_spanned(
+                        pat,
+                        "template arguments must be plain identifiers",
+                    ));
+                }
+            },
+
+            FnArg::Receiver(x) => {
+                return Err(syn::Error::new_spanned(
+                    x,
+                    "`#[template]` cannot be used on a method",
+                ));
+            }
+        }
+    }
+
+    let buf = Ident::new("__B", Span::mixed_site());
+    let write_ident = Ident::new("__s", Span::mixed_site());
+
+    // `Html` bounds on the template's parameters name the default buffer, so
+    // they are dropped from the struct and written against the buffer being
+    // rendered into instead. This lets children passed as `impl Html` be
+    // rendered into any buffer.
+    let mut impl_generics = generics.clone();
+    map_html_bounds(&mut generics, |_| false);
+    map_html_bounds(&mut impl_generics, |x| {
+        let last = x.path.segments.last_mut().unwrap();
+        last.arguments = PathArguments::AngleBracketed(parse_quote!(<#buf>));
+        true
+    });
+
+    let docs = attrs.iter().filter(|x| x.path().is_ident("doc"));
+
+    let field_idents = fields.iter().map(|x| x.ident).collect::<Vec<_>>();
+    let field_types = fields.iter().map(|x| &x.ty).collect::<Vec<_>>();
+
+    let (_, ty_generics, where_clause) = generics.split_for_impl();
+
+    impl_generics
+        .params
+        .push(GenericParam::Type(TypeParam::from(buf.clone())));
+    impl_generics
+        .make_where_clause()
+        .predicates
+        .push(parse_quote!(#buf: #crate_path::Buffer + ?::core::marker::Sized));
+
+    let (impl_generics, _, impl_where_clause) = impl_generics.split_for_impl();
+
+    let fn_ident = &sig.ident;
+    let fn_generics = &generics;
+    let fn_where_clause = &generics.where_clause;
+    let constness = &sig.constness;
+    let unsafety = &sig.unsafety;
+
+    let fn_doc = format!("Creates a [`{name}`] template.");
+    let fn_doc = if attrs.iter().any(|x| x.path().is_ident("doc")) {
+        quote! {}
+    } else {
+        quote! { #[doc = #fn_doc] }
+    };
+
+    let field_vis = fields.iter().map(|_| &vis);
+    let fn_inputs = fields
+        .iter()
+        .map(|Field { ident, ty, .. }| quote! { #ident: #ty });
+
+    let builder = builder(&crate_path, &vis, &name, &generics, &fields);
+
+    Ok(quote! {
+        #(#docs)*
+        #vis struct #name #generics #where_clause {
+            #(#field_vis #field_idents: #field_types,)*
+        }
+
+        #builder
+
+        impl #impl_generics #crate_path::Html<#buf> for #name #ty_generics #impl_where_clause {
+            #[inline]
+            #[allow(clippy::redundant_closure_call)]
+            fn write(self, #write_ident: &mut #buf) {
+                let Self { #(#field_pats),* } = self;
+
+                // The body is called as a closure so that `return` inside it
+                // returns the template rather than leaving `write`.
+                #crate_path::Html::write((move || #block)(), #write_ident)
+            }
+        }
+
+        #(#attrs)*
+        #fn_doc
+        #vis #constness #unsafety fn #fn_ident #fn_generics (#(#fn_inputs),*) -> #name #ty_generics #fn_where_clause {
+            #name { #(#field_idents),* }
+        }
+    })
+}
+
+/// Checks that the function is declared to return `impl Html`, since the struct
+/// replaces the function's return value.
+fn check_output(output: &ReturnType, span: Span) -> syn::Result<()> {
+    let message = "`#[template]` functions must return `impl Html`";
+
+    let ReturnType::Type(_, ty) = output else {
+        return Err(syn::Error::new(span, message));
+    };
+
+    match &**ty {
+        Type::ImplTrait(x)
+            if x.bounds.iter().any(|x| {
+                matches!(x, TypeParamBound::Trait(TraitBound { path, .. })
+                    if path.segments.last().is_some_and(|x| x.ident == "Html"))
+            }) =>
+        {
+            Ok(())
+        }
+
+        ty => Err(syn::Error::new_spanned(ty, message)),
+    }
+}
+
+/// A prop of a component, which becomes a field of its struct and a setter on
+/// its builder.
+pub struct Field<'a> {
+    pub ident: &'a Ident,
+    pub vis: &'a Visibility,
+    pub ty: Type,
+    pub slot: bool,
+    pub optional: bool,
+}
+
+/// Returns whether the argument is marked as a slot with `#[slot]`, and whether
+/// it is an optional slot marked with `#[slot(optional)]`.
+fn parse_slot_attrs(attrs: &[Attribute]) -> syn::Result<(bool, bool)> {
+    let mut slot = false;
+    let mut optional = false;
+
+    for attr in attrs.iter().filter(|x| x.path().is_ident("slot")) {
+        slot = true;
+
+        if let Meta::List(_) = &attr.meta {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("optional") {
+                    optional = true;
+                    Ok(())
+                } else {
+                    Err(meta.error("unknown slot option"))
+                }
+            })?;
+        }
+    }
+
+    Ok((slot, optional))
+}
+
+fn is_type_param(ty: &Type, generics: &Generics) -> bool {
+    match ty {
+        Type::Path(TypePath { qself: None, path }) => path
+            .get_ident()
+            .is_some_and(|ident| generics.type_params().any(|x| x.ident == *ident)),
+
+        _ => false,
+    }
+}
+
+/// Generates the builder used by component invocations such as
+/// `@Card(title = x) { ... }`.
+///
+/// The builder tracks which props have been set in its type parameters, so a
+/// missing prop is a compile error, and each setter is only available while its
+/// prop is unset, so a repeated prop is too. Slots are set with setters named
+/// `__slot_name`, so that `@slot` cannot fill a plain prop, and optional slots
+/// default to `()` when they are left unset.
+pub fn builder<C>(
+    crate_path: &C,
+    vis: &Visibility,
+    name: &Ident,
+    generics: &Generics,
+    fields: &[Field],
+) -> TokenStream
+where
+    C: ToTokens,
+{
+    let builder = format_ident!("__{}Builder", name);
+
+    let idents = fields.iter().map(|x| x.ident).collect::<Vec<_>>();
+    let states = (0..fields.len())
+        .map(|i| format_ident!("__P{}", i))
+        .collect::<Vec<_>>();
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let initial = fields.iter().map(|_| quote! { #crate_path::__Missing });
+
+    let setters = fields.iter().enumerate().map(|(i, field)| {
+        let Field { ident, ty, .. } = field;
+        let field_vis = field.vis;
+
+        let method = if field.slot {
+            format_ident!("__slot_{}", ident, span = ident.span())
+        } else {
+            (*ident).clone()
+        };
+
+        let mut setter_generics = generics.clone();
+        setter_generics.params.extend(
+            states
+                .iter()
+                .enumerate()
+                .filter(|(j, _)| i != *j)
+                .map(|(_, x)| GenericParam::Type(TypeParam::from(x.clone()))),
+        );
+
+        let (setter_impl_generics, _, _) = setter_generics.split_for_impl();
+
+        let before = states.iter().enumerate().map(|(j, x)| {
+            if i == j {
+                quote! { #crate_path::__Missing }
+            } else {
+                quote! { #x }
+            }
+        });
+
+        let after = states.iter().enumerate().map(|(j, x)| {
+            if i == j {
+                quote! { #crate_path::__Set<#ty> }
+            } else {
+                quote! { #x }
+            }
+        });
+
+        let values = idents.iter().enumerate().map(|(j, x)| {
+            if i == j {
+                quote! { #x: #crate_path::__Set(#x) }
+            } else {
+                quote! { #x: self.#x }
+            }
+        });
+
+        quote! {
+            impl #setter_impl_generics #builder<#name #ty_generics, #(#before),*> #where_clause {
+                #[doc(hidden)]
+                #[inline]
+                #field_vis fn #method(self, #ident: #ty) -> #builder<#name #ty_generics, #(#after),*> {
+                    #builder {
+                        __target: ::core::marker::PhantomData,
+                        #(#values,)*
+                    }
+                }
+            }
+        }
+    });
+
+    // Each prop gets its own trait, so that a missing prop is reported by name.
+    let args = format_ident!("__{}Args", name);
+
+    let arg_traits = fields.iter().map(|Field { ident, slot, .. }| {
+        let (message, label) = if *slot {
+            (
+                format!("missing slot `{ident}` for `{name}`"),
+                format!("add `@slot {ident} {{ ... }}` to the children of this component"),
+            )
+        } else if *ident == "children" {
+            (
+                format!("missing children for `{name}`"),
+                "add a `{ ... }` block after this component".to_owned(),
+            )
+        } else {
+            (
+                format!("missing argument `{ident}` for `{name}`"),
+                format!("add `{ident} = ...` to the arguments of this component"),
+            )
+        };
+
+        quote! {
+            #[diagnostic::on_unimplemented(message = #message, label = #label)]
+            pub trait #ident<T> {
+                fn into_inner(self) -> T;
+            }
+        }
+    });
+
+    let arg_impls = fields.iter().map(
+        |Field {
+             ident, optional, ..
+         }| {
+            let default = optional.then(|| {
+                quote! {
+                    impl #args::#ident<()> for #crate_path::__Missing {
+                        #[inline]
+                        fn into_inner(self) {}
+                    }
+                }
+            });
+
+            quote! {
+                impl<T> #args::#ident<T> for #crate_path::__Set<T> {
+                    #[inline]
+                    fn into_inner(self) -> T {
+                        self.0
+                    }
+                }
+
+                #default
+            }
+        },
+    );
+
+    let mut build_generics = generics.clone();
+    build_generics.params.extend(
+        states
+            .iter()
+            .map(|x| GenericParam::Type(TypeParam::from(x.clone()))),
+    );
+
+    let (build_impl_generics, _, _) = build_generics.split_for_impl();
+
+    let bounds = fields
+        .iter()
+        .zip(&states)
+        .map(|(Field { ident, ty, .. }, state)| {
+            quote_spanned! { ident.span()=> #state: #args::#ident<#ty> }
+        });
+
+    quote! {
+        #[doc(hidden)]
+        #[allow(non_camel_case_types, non_snake_case)]
+        #vis mod #args {
+            #(#arg_traits)*
+        }
+
+        #(#arg_impls)*
+
+        #[doc(hidden)]
+        #vis struct #builder<__T, #(#states),*> {
+            __target: ::core::marker::PhantomData<fn() -> __T>,
+            #(#idents: #states,)*
+        }
+
+        impl #impl_generics #name #ty_generics #where_clause {
+            #[doc(hidden)]
+            #[inline]
+            #vis fn __builder() -> #builder<Self, #(#initial),*> {
+                #builder {
+                    __target: ::core::marker::PhantomData,
+                    #(#idents: #crate_path::__Missing,)*
+                }
+            }
+        }
+
+        #(#setters)*
+
+        impl #build_impl_generics #builder<#name #ty_generics, #(#states),*> #where_clause {
+            #[doc(hidden)]
+            #[inline]
+            #vis fn __build(self) -> #name #ty_generics
+            where
+                #(#bounds,)*
+            {
+                #name {
+                    #(#idents: #args::#idents::into_inner(self.#idents),)*
+                }
+            }
+        }
+    }
+}
+
+/// Keeps or rewrites each bare `Html` bound in `generics`, removing it when `f`
+/// returns `false`.
+fn map_html_bounds<F>(generics: &mut Generics, mut f: F)
+where
+    F: FnMut(&mut TraitBound) -> bool,
+{
+    let mut map = |bounds: &mut Punctuated<TypeParamBound, Token![+]>| {
+        *bounds = std::mem::take(bounds)
+            .into_iter()
+            .filter_map(|mut bound| match &mut bound {
+                TypeParamBound::Trait(x) if is_html_bound(x) => f(x).then_some(bound),
+                _ => Some(bound),
+            })
+            .collect();
+    };
+
+    for param in generics.type_params_mut() {
+        map(&mut param.bounds);
+    }
+
+    if let Some(where_clause) = &mut generics.where_clause {
+        for predicate in &mut where_clause.predicates {
+            if let WherePredicate::Type(x) = predicate {
+                map(&mut x.bounds);
+            }
+        }
+    }
+}
+
+fn is_html_bound(bound: &TraitBound) -> bool {
+    bound
+        .path
+        .segments
+        .last()
+        .is_some_and(|x| x.ident == "Html" && x.arguments.is_none())
+}
+
+fn to_upper_camel_case(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut upper = true;
+
+    for ch in s.trim_start_matches("r#").chars() {
+        if ch == '_' {
+            upper = true;
+        } else if upper {
+            out.extend(ch.to_uppercase());
+            upper = false;
+        } else {
+            out.push(ch);
+        }
+    }
+
+    out
+}
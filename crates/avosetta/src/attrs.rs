@@ -0,0 +1,156 @@
+use crate::{__Attr, Buffer, ByRef, EscapeBuffer, EscapeContext, Html, HtmlRef};
+
+/// Renders a collection of attributes, each preceded by a space.
+///
+/// This is the runtime representation of a spread such as `div[..extra]` in
+/// [`asx!`](crate::asx). `T` can be any collection of key-value pairs, such as
+/// a `Vec<(String, String)>` or a `HashMap<&str, &str>`, and each entry is
+/// written through [`Attr`](crate::Attr), so its value is escaped for the
+/// context the key implies.
+///
+/// Keys often come from untrusted input, so entries whose key is not a valid
+/// attribute name are skipped, as are `on*` event handlers and `srcdoc`, whose
+/// values would be run as script or parsed as markup. The values of URL
+/// attributes such as `href` are checked like a [`Url`](crate::Url), so a
+/// `javascript:` URL is written as [`Url::BLOCKED`](crate::Url::BLOCKED).
+///
+/// # Example
+///
+/// ```rust
+/// use avosetta::{Attrs, Html};
+///
+/// let extra = vec![
+///     ("data-id", "7"),
+///     ("title", "a \"quote\""),
+///     ("onclick", "alert(1)"),
+///     ("x\"><script>", "evil"),
+///     ("href", "javascript:alert(1)"),
+/// ];
+///
+/// let mut output = String::new();
+/// Attrs(extra).write(&mut output);
+///
+/// assert_eq!(
+///     output,
+///     r#" data-id="7" title="a &quot;quote&quot;" href="about:invalid""#,
+/// );
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Attrs<T>(pub T);
+
+impl Attrs<()> {
+    /// Returns `true` if an entry with the key `name` is written.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use avosetta::Attrs;
+    ///
+    /// assert!(Attrs::is_allowed("aria-label"));
+    /// assert!(!Attrs::is_allowed("a b"));
+    /// assert!(!Attrs::is_allowed("onload"));
+    /// ```
+    pub fn is_allowed(name: &str) -> bool {
+        // Attribute names cannot be empty, and cannot contain whitespace,
+        // controls, quotes, `>`, `/`, `=` or noncharacters. `<` and `&` are
+        // rejected as well, since they are never useful and only ever escaped.
+        let valid = !name.is_empty()
+            && name.chars().all(|x| {
+                !x.is_whitespace()
+                    && !x.is_control()
+                    && !matches!(x, '"' | '\'' | '>' | '/' | '=' | '<' | '&')
+                    && !matches!(x, '\u{FDD0}'..='\u{FDEF}')
+                    && (x as u32 & 0xFFFE) != 0xFFFE
+            });
+
+        let dangerous = name.get(..2).is_some_and(|x| x.eq_ignore_ascii_case("on"))
+            || name.eq_ignore_ascii_case("srcdoc");
+
+        valid && !dangerous
+    }
+}
+
+impl<T, B> Html<B> for Attrs<T>
+where
+    T: IntoIterator,
+    T::Item: AttrEntry<B>,
+    B: Buffer + ?Sized,
+{
+    fn write(self, s: &mut B) {
+        for entry in self.0 {
+            entry.write_entry(s);
+        }
+    }
+}
+
+impl<T, B> HtmlRef<B> for Attrs<T>
+where
+    T: IntoIterator + Copy,
+    T::Item: AttrEntry<B>,
+    B: Buffer + ?Sized,
+{
+    #[inline]
+    fn write_ref(&self, s: &mut B) {
+        Attrs(self.0).write(s);
+    }
+}
+
+/// An entry of an [`Attrs`] collection.
+///
+/// This is implemented for key-value tuples and references to them, so that
+/// both `Vec<(K, V)>` and `&Vec<(K, V)>` can be rendered. Maps can be rendered
+/// by value, or by reference when their values are [`Copy`], such as
+/// `&HashMap<String, &str>`.
+pub trait AttrEntry<B>
+where
+    B: Buffer + ?Sized,
+{
+    /// Appends a space and the attribute to `s`, unless the key is not
+    /// allowed.
+    fn write_entry(self, s: &mut B);
+}
+
+impl<K, V, B> AttrEntry<B> for (K, V)
+where
+    K: AsRef<str>,
+    V: for<'a> Html<EscapeBuffer<'a, B>>,
+    B: Buffer + ?Sized,
+{
+    fn write_entry(self, s: &mut B) {
+        let (key, value) = self;
+
+        if !Attrs::is_allowed(key.as_ref()) || value.is_none() || value.is_false() {
+            return;
+        }
+
+        let key = key.as_ref();
+        let context = EscapeContext::for_attribute(key);
+
+        s.push(' ');
+
+        if context != EscapeContext::Url || value.is_true() {
+            return __Attr(key, value, context).write(s);
+        }
+
+        s.push_text(key);
+        s.push_str("=\"");
+
+        let mut url = EscapeBuffer::checking_url(s);
+        value.write(&mut url);
+        url.finish_url();
+
+        s.push('"');
+    }
+}
+
+impl<K, V, B> AttrEntry<B> for &(K, V)
+where
+    K: AsRef<str>,
+    V: for<'a> HtmlRef<EscapeBuffer<'a, B>>,
+    B: Buffer + ?Sized,
+{
+    #[inline]
+    fn write_entry(self, s: &mut B) {
+        (self.0.as_ref(), ByRef(&self.1)).write_entry(s);
+    }
+}
@@ -0,0 +1,148 @@
+use crate::{Buffer, Html, HtmlRef};
+
+/// A list of class names, written separated by spaces.
+///
+/// Names are added with [`ClassList::with`], or conditionally with
+/// [`ClassList::toggle`]. Each string may hold several names separated by
+/// whitespace, and a name that is already in the list is not added again. An
+/// empty list is treated like [`None`], so [`Attr`](crate::Attr) and templates
+/// leave out a `class` attribute whose value is empty.
+///
+/// In [`asx!`](crate::asx), `class:name=condition` builds a `ClassList` for the
+/// element from its `class` literals and toggles.
+///
+/// # Example
+///
+/// ```rust
+/// use avosetta::{asx, Attr, ClassList, Html};
+///
+/// let active = true;
+/// let disabled = false;
+///
+/// let classes = ClassList::new()
+///     .with("button primary")
+///     .toggle("active", active)
+///     .toggle("disabled", disabled)
+///     .with("button");
+///
+/// let page = asx! {
+///     button[class=classes] { "Save" }
+/// };
+///
+/// let mut output = String::new();
+/// page.write(&mut output);
+/// Attr("class", ClassList::new().toggle("hidden", false)).write(&mut output);
+///
+/// assert_eq!(output, r#"<button class="button primary active">Save</button>"#);
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct ClassList<'a> {
+    names: Vec<&'a str>,
+}
+
+impl<'a> ClassList<'a> {
+    /// Creates an empty class list.
+    #[inline]
+    pub const fn new() -> Self {
+        Self { names: Vec::new() }
+    }
+
+    /// Adds the whitespace-separated class names in `names`.
+    #[inline]
+    pub fn with(mut self, names: &'a str) -> Self {
+        self.push(names);
+        self
+    }
+
+    /// Adds the whitespace-separated class names in `names` if `on` is `true`.
+    #[inline]
+    pub fn toggle(mut self, names: &'a str, on: bool) -> Self {
+        if on {
+            self.push(names);
+        }
+
+        self
+    }
+
+    /// Adds the whitespace-separated class names in `names`, skipping any that
+    /// are already in the list.
+    pub fn push(&mut self, names: &'a str) {
+        for name in names.split_ascii_whitespace() {
+            if !self.names.contains(&name) {
+                self.names.push(name);
+            }
+        }
+    }
+
+    /// Returns `true` if the list holds no class names.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    /// Returns an iterator over the class names, in the order they were first
+    /// added.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = &'a str> + '_ {
+        self.names.iter().copied()
+    }
+}
+
+impl<'a> Extend<&'a str> for ClassList<'a> {
+    #[inline]
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = &'a str>,
+    {
+        for names in iter {
+            self.push(names);
+        }
+    }
+}
+
+impl<'a> FromIterator<&'a str> for ClassList<'a> {
+    #[inline]
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = &'a str>,
+    {
+        let mut list = Self::new();
+        list.extend(iter);
+        list
+    }
+}
+
+impl<B> Html<B> for ClassList<'_>
+where
+    B: Buffer + ?Sized,
+{
+    #[inline]
+    fn write(self, s: &mut B) {
+        self.write_ref(s);
+    }
+
+    #[inline]
+    fn is_none(&self) -> bool {
+        self.is_empty()
+    }
+}
+
+impl<B> HtmlRef<B> for ClassList<'_>
+where
+    B: Buffer + ?Sized,
+{
+    fn write_ref(&self, s: &mut B) {
+        for (i, name) in self.names.iter().enumerate() {
+            if i > 0 {
+                s.push(' ');
+            }
+
+            s.push_text(name);
+        }
+    }
+
+    #[inline]
+    fn is_none(&self) -> bool {
+        self.is_empty()
+    }
+}
@@ -0,0 +1,554 @@
+use crate::{Buffer, Html, HtmlRef, Url};
+
+/// The context that dynamic text is escaped for.
+///
+/// Entity escaping keeps text from being parsed as markup, but it is not
+/// enough inside an attribute whose value is parsed again as a URL, a script,
+/// or a stylesheet. Each context describes how text must be escaped to stay
+/// inert there, while keeping the result safe inside a double-quoted attribute
+/// value.
+///
+/// [`Attr`](crate::Attr) and [`asx!`](crate::asx) choose a context from the
+/// attribute name with [`EscapeContext::for_attribute`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EscapeContext {
+    /// Text content of an element, escaped with HTML entities.
+    #[default]
+    Text,
+
+    /// A quoted attribute value, escaped with HTML entities.
+    Attribute,
+
+    /// A whole URL, such as the value of `href` or `src`.
+    ///
+    /// Bytes that cannot appear in a URL, including quotes, spaces, and
+    /// non-ASCII characters, are percent-encoded, and `&` is written as an
+    /// entity. Characters that separate the parts of a URL are kept, so the
+    /// scheme of the URL is not checked.
+    Url,
+
+    /// A single URL component, such as a path segment or a query parameter.
+    ///
+    /// Every byte other than an ASCII letter, digit, `-`, `.`, `_`, or `~` is
+    /// percent-encoded.
+    UrlComponent,
+
+    /// The contents of a JavaScript string literal, such as the value of an
+    /// `on*` event handler attribute.
+    ///
+    /// Quotes, backslashes, HTML-sensitive characters, and line terminators are
+    /// written as JavaScript escape sequences, so the text cannot end the string
+    /// literal, the attribute, or a `<script>` element.
+    JsString,
+
+    /// A CSS value, such as the value of a `style` attribute.
+    ///
+    /// Quotes, backslashes, braces, HTML-sensitive characters, and control
+    /// characters are written as CSS escape sequences, so the text cannot end a
+    /// string, a rule block, or the attribute.
+    Css,
+}
+
+const URL_ATTRIBUTES: &[&str] = &[
+    "action",
+    "background",
+    "cite",
+    "codebase",
+    "data",
+    "formaction",
+    "href",
+    "icon",
+    "longdesc",
+    "manifest",
+    "poster",
+    "src",
+    "xlink:href",
+];
+
+impl EscapeContext {
+    /// Returns the context for the value of the attribute called `name`.
+    ///
+    /// URL attributes such as `href` and `src` use [`EscapeContext::Url`],
+    /// event handler attributes starting with `on` use
+    /// [`EscapeContext::JsString`], `style` uses [`EscapeContext::Css`], and
+    /// every other attribute uses [`EscapeContext::Attribute`]. Names are
+    /// compared case-insensitively.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use avosetta::EscapeContext;
+    ///
+    /// assert_eq!(EscapeContext::for_attribute("href"), EscapeContext::Url);
+    /// assert_eq!(EscapeContext::for_attribute("onClick"), EscapeContext::JsString);
+    /// assert_eq!(EscapeContext::for_attribute("style"), EscapeContext::Css);
+    /// assert_eq!(EscapeContext::for_attribute("title"), EscapeContext::Attribute);
+    /// ```
+    pub const fn for_attribute(name: &str) -> Self {
+        let name = name.as_bytes();
+
+        if eq_ignore_case(name, b"style") {
+            return Self::Css;
+        }
+
+        if name.len() > 2 && eq_ignore_case(name.split_at(2).0, b"on") {
+            return Self::JsString;
+        }
+
+        let mut i = 0;
+
+        while i < URL_ATTRIBUTES.len() {
+            if eq_ignore_case(name, URL_ATTRIBUTES[i].as_bytes()) {
+                return Self::Url;
+            }
+
+            i += 1;
+        }
+
+        Self::Attribute
+    }
+
+    /// Escapes `s` for this context and appends it to `buf`.
+    pub fn escape<B>(self, s: &str, buf: &mut B)
+    where
+        B: Buffer + ?Sized,
+    {
+        match self {
+            Self::Text | Self::Attribute => buf.push_text(s),
+            Self::Url => escape_url(s, buf, b"-._~:/?#[]@!$&()*+,;=%"),
+            Self::UrlComponent => escape_url(s, buf, b"-._~"),
+            Self::JsString => escape_js(s, buf),
+            Self::Css => escape_css(s, buf),
+        }
+    }
+}
+
+/// Compares `a` to the lowercase ASCII string `b`, ignoring the case of `a`.
+const fn eq_ignore_case(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+
+    let mut i = 0;
+
+    while i < a.len() {
+        if a[i].to_ascii_lowercase() != b[i] {
+            return false;
+        }
+
+        i += 1;
+    }
+
+    true
+}
+
+const HEX: &[u8; 16] = b"0123456789ABCDEF";
+
+fn escape_url<B>(s: &str, buf: &mut B, allowed: &[u8])
+where
+    B: Buffer + ?Sized,
+{
+    buf.reserve(s.len());
+
+    let mut start = 0;
+
+    for (i, byte) in s.bytes().enumerate() {
+        let allowed = byte.is_ascii_alphanumeric() || allowed.contains(&byte);
+
+        if allowed && byte != b'&' {
+            continue;
+        }
+
+        // Bytes of a multibyte character are encoded one by one, so `i` is not
+        // always a character boundary.
+        if start < i {
+            buf.push_str(&s[start..i]);
+        }
+
+        if allowed {
+            buf.push_str("&amp;");
+        } else {
+            push_hex(buf, "%", byte, "");
+        }
+
+        start = i + 1;
+    }
+
+    buf.push_str(&s[start..]);
+}
+
+fn escape_js<B>(s: &str, buf: &mut B)
+where
+    B: Buffer + ?Sized,
+{
+    buf.reserve(s.len());
+
+    let mut start = 0;
+
+    for (i, ch) in s.char_indices() {
+        let escape = match ch {
+            '\\' => "\\\\",
+            '\n' => "\\n",
+            '\r' => "\\r",
+            '\t' => "\\t",
+            '"' => "\\x22",
+            '&' => "\\x26",
+            '\'' => "\\x27",
+            '<' => "\\x3C",
+            '>' => "\\x3E",
+            '`' => "\\x60",
+            '\u{2028}' => "\\u2028",
+            '\u{2029}' => "\\u2029",
+
+            '\0'..='\x1f' | '\x7f' => {
+                buf.push_str(&s[start..i]);
+                push_hex(buf, "\\x", ch as u8, "");
+                start = i + 1;
+                continue;
+            }
+
+            _ => continue,
+        };
+
+        buf.push_str(&s[start..i]);
+        buf.push_str(escape);
+        start = i + ch.len_utf8();
+    }
+
+    buf.push_str(&s[start..]);
+}
+
+fn escape_css<B>(s: &str, buf: &mut B)
+where
+    B: Buffer + ?Sized,
+{
+    buf.reserve(s.len());
+
+    let mut start = 0;
+
+    for (i, byte) in s.bytes().enumerate() {
+        if !matches!(
+            byte,
+            b'\0'..=b'\x1f' | b'\x7f' | b'\\' | b'"' | b'\'' | b'&' | b'<' | b'>' | b'{' | b'}'
+        ) {
+            continue;
+        }
+
+        buf.push_str(&s[start..i]);
+
+        // CSS escapes end with a space, so that a following hex digit is not
+        // read as part of the escape.
+        push_hex(buf, "\\", byte, " ");
+        start = i + 1;
+    }
+
+    buf.push_str(&s[start..]);
+}
+
+#[inline]
+fn push_hex<B>(buf: &mut B, prefix: &str, byte: u8, suffix: &str)
+where
+    B: Buffer + ?Sized,
+{
+    let hex = [HEX[usize::from(byte >> 4)], HEX[usize::from(byte & 0xf)]];
+
+    buf.push_str(prefix);
+    // The digits are always ASCII.
+    buf.push_str(std::str::from_utf8(&hex).unwrap_or_default());
+    buf.push_str(suffix);
+}
+
+/// A [`Buffer`] adapter that escapes text for an [`EscapeContext`].
+///
+/// Markup written with [`Buffer::push_str`] passes through unchanged, while
+/// text written with [`Buffer::push_text`] is escaped for the context.
+#[derive(Debug)]
+pub struct EscapeBuffer<'a, B>
+where
+    B: Buffer + ?Sized,
+{
+    inner: &'a mut B,
+    context: EscapeContext,
+
+    /// A URL collected by [`EscapeBuffer::checking_url`], which is written
+    /// once its scheme can be checked.
+    url: Option<String>,
+}
+
+impl<'a, B> EscapeBuffer<'a, B>
+where
+    B: Buffer + ?Sized,
+{
+    /// Creates an adapter that escapes text for `context` and writes it to
+    /// `inner`.
+    #[inline]
+    pub fn new(inner: &'a mut B, context: EscapeContext) -> Self {
+        Self {
+            inner,
+            context,
+            url: None,
+        }
+    }
+
+    /// Creates an adapter that collects a URL, which
+    /// [`EscapeBuffer::finish_url`] writes to `inner` if its scheme is allowed
+    /// by [`Url::new`].
+    #[inline]
+    pub(crate) fn checking_url(inner: &'a mut B) -> Self {
+        Self {
+            inner,
+            context: EscapeContext::Url,
+            url: Some(String::new()),
+        }
+    }
+
+    /// Writes the URL collected by [`EscapeBuffer::checking_url`], or
+    /// [`Url::BLOCKED`] if its scheme is not allowed.
+    pub(crate) fn finish_url(self) {
+        if let Some(url) = self.url {
+            self.context.escape(Url::new(url).as_allowed(), self.inner);
+        }
+    }
+
+    /// Returns the context that text is escaped for.
+    #[inline]
+    pub const fn context(&self) -> EscapeContext {
+        self.context
+    }
+}
+
+impl<B> Buffer for EscapeBuffer<'_, B>
+where
+    B: Buffer + ?Sized,
+{
+    #[inline]
+    fn push_str(&mut self, s: &str) {
+        match &mut self.url {
+            Some(url) => url.push_str(s),
+            None => self.inner.push_str(s),
+        }
+    }
+
+    #[inline]
+    fn push(&mut self, ch: char) {
+        match &mut self.url {
+            Some(url) => url.push(ch),
+            None => self.inner.push(ch),
+        }
+    }
+
+    #[inline]
+    fn reserve(&mut self, additional: usize) {
+        match &mut self.url {
+            Some(url) => url.reserve(additional),
+            None => self.inner.reserve(additional),
+        }
+    }
+
+    #[inline]
+    fn push_text(&mut self, s: &str) {
+        match &mut self.url {
+            Some(url) => url.push_str(s),
+            None => self.context.escape(s, self.inner),
+        }
+    }
+}
+
+macro_rules! context_wrapper {
+    ($(#[$attr:meta])* $ident:ident => $context:ident) => {
+        $(#[$attr])*
+        #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub struct $ident<T>(pub T);
+
+        impl<T, B> Html<B> for $ident<T>
+        where
+            T: for<'a> Html<EscapeBuffer<'a, B>>,
+            B: Buffer + ?Sized,
+        {
+            #[inline]
+            fn write(self, s: &mut B) {
+                self.0
+                    .write(&mut EscapeBuffer::new(s, EscapeContext::$context));
+            }
+        }
+
+        impl<T, B> HtmlRef<B> for $ident<T>
+        where
+            T: for<'a> HtmlRef<EscapeBuffer<'a, B>>,
+            B: Buffer + ?Sized,
+        {
+            #[inline]
+            fn write_ref(&self, s: &mut B) {
+                self.0
+                    .write_ref(&mut EscapeBuffer::new(s, EscapeContext::$context));
+            }
+        }
+    };
+}
+
+context_wrapper! {
+    /// Renders a value escaped as a whole URL.
+    ///
+    /// See [`EscapeContext::Url`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use avosetta::{EscapeUrl, Html};
+    ///
+    /// let mut output = String::new();
+    /// EscapeUrl("/search?q=a b&lang=\"en\"").write(&mut output);
+    ///
+    /// assert_eq!(output, "/search?q=a%20b&amp;lang=%22en%22");
+    /// ```
+    EscapeUrl => Url
+}
+
+context_wrapper! {
+    /// Renders a value escaped as a single URL component.
+    ///
+    /// See [`EscapeContext::UrlComponent`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use avosetta::{EscapeUrlComponent, Html};
+    ///
+    /// let mut output = String::from("/search?q=");
+    /// EscapeUrlComponent("fish & chips").write(&mut output);
+    ///
+    /// assert_eq!(output, "/search?q=fish%20%26%20chips");
+    /// ```
+    EscapeUrlComponent => UrlComponent
+}
+
+context_wrapper! {
+    /// Renders a value escaped as the contents of a JavaScript string literal.
+    ///
+    /// See [`EscapeContext::JsString`]. This also makes text safe to place in a
+    /// string literal inside a `<script>` element.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use avosetta::{EscapeJs, Html};
+    ///
+    /// let mut output = String::new();
+    /// EscapeJs("it's </script>").write(&mut output);
+    ///
+    /// assert_eq!(output, r"it\x27s \x3C/script\x3E");
+    /// ```
+    EscapeJs => JsString
+}
+
+context_wrapper! {
+    /// Renders a value escaped as a CSS value.
+    ///
+    /// See [`EscapeContext::Css`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use avosetta::{EscapeCss, Html};
+    ///
+    /// let mut output = String::new();
+    /// EscapeCss("red}body{color:blue").write(&mut output);
+    ///
+    /// assert_eq!(output, r"red\7D body\7B color:blue");
+    /// ```
+    EscapeCss => Css
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn escape(context: EscapeContext, s: &str) -> String {
+        let mut buf = String::new();
+        context.escape(s, &mut buf);
+        buf
+    }
+
+    #[test]
+    fn escapes_text_and_attributes_with_entities() {
+        assert_eq!(
+            escape(EscapeContext::Text, "<a & 'b'>"),
+            "&lt;a &amp; &#39;b&#39;&gt;"
+        );
+        assert_eq!(
+            escape(EscapeContext::Attribute, r#"say "hi""#),
+            "say &quot;hi&quot;"
+        );
+    }
+
+    #[test]
+    fn escapes_urls() {
+        assert_eq!(
+            escape(EscapeContext::Url, "/a b?x=1&y=\"é\""),
+            "/a%20b?x=1&amp;y=%22%C3%A9%22"
+        );
+        assert_eq!(
+            escape(EscapeContext::UrlComponent, "a/b?c=d&e"),
+            "a%2Fb%3Fc%3Dd%26e"
+        );
+    }
+
+    #[test]
+    fn escapes_js_strings() {
+        assert_eq!(
+            escape(EscapeContext::JsString, "it's \"</script>\"\n"),
+            r"it\x27s \x22\x3C/script\x3E\x22\n"
+        );
+    }
+
+    #[test]
+    fn escapes_css_values() {
+        assert_eq!(escape(EscapeContext::Css, "a\"b}"), r"a\22 b\7D ");
+    }
+
+    #[test]
+    fn chooses_context_from_attribute_name() {
+        assert_eq!(EscapeContext::for_attribute("HREF"), EscapeContext::Url);
+        assert_eq!(
+            EscapeContext::for_attribute("xlink:href"),
+            EscapeContext::Url
+        );
+        assert_eq!(
+            EscapeContext::for_attribute("onclick"),
+            EscapeContext::JsString
+        );
+        assert_eq!(EscapeContext::for_attribute("STYLE"), EscapeContext::Css);
+        assert_eq!(EscapeContext::for_attribute("on"), EscapeContext::Attribute);
+        assert_eq!(
+            EscapeContext::for_attribute("class"),
+            EscapeContext::Attribute
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "macros")]
+    fn escapes_event_handlers_and_styles() {
+        let handler = "');alert('hi";
+        let style = r#"red;}</style>"#;
+
+        let mut html = String::new();
+        crate::asx! { button[onclick=handler, style=style] {} }.write(&mut html);
+
+        assert_eq!(
+            html,
+            r#"<button onclick="\x27);alert(\x27hi" style="red;\7D \3C /style\3E "></button>"#
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "macros")]
+    fn writes_literal_event_handlers_and_styles_as_is() {
+        let mut html = String::new();
+        crate::asx! { button[onclick="alert('hi')", style="color:red"] {} }.write(&mut html);
+
+        assert_eq!(
+            html,
+            r#"<button onclick="alert(&apos;hi&apos;)" style="color:red"></button>"#
+        );
+    }
+}
@@ -0,0 +1,215 @@
+use std::io::{self, Write};
+
+use crate::{Buffer, Html};
+
+/// The default number of bytes an [`IoBuffer`] holds before writing them out.
+pub const DEFAULT_LIMIT: usize = 8 * 1024;
+
+/// A [`Buffer`] that streams rendered HTML to a [`std::io::Write`].
+///
+/// Output is collected in an internal buffer and written to the underlying
+/// writer in chunks whenever the buffer would grow past its limit, so memory
+/// use stays bounded no matter how large the rendered document is. Writes that
+/// do not fit in the space left in the buffer, such as the large static runs
+/// combined by [`asx!`](crate::asx), are passed straight to the writer after
+/// the buffered output, without being copied.
+///
+/// [`Buffer`] is infallible, so the first I/O error is stored and every later
+/// write is skipped. The error is returned by [`IoBuffer::finish`].
+///
+/// Dropping an `IoBuffer` does not flush it: output still held in the buffer
+/// is discarded, along with any stored error. Call [`IoBuffer::finish`] or
+/// [`IoBuffer::flush`] once rendering is complete.
+///
+/// Most callers should use [`write_io`] instead of constructing an `IoBuffer`
+/// directly.
+#[derive(Debug)]
+pub struct IoBuffer<W>
+where
+    W: Write,
+{
+    writer: W,
+    buf: Vec<u8>,
+    limit: usize,
+    error: Option<io::Error>,
+}
+
+impl<W> IoBuffer<W>
+where
+    W: Write,
+{
+    /// Creates a buffer that writes to `writer` in chunks of up to
+    /// [`DEFAULT_LIMIT`] bytes.
+    #[inline]
+    pub fn new(writer: W) -> Self {
+        Self::with_limit(DEFAULT_LIMIT, writer)
+    }
+
+    /// Creates a buffer that writes to `writer` in chunks of up to `limit`
+    /// bytes.
+    #[inline]
+    pub fn with_limit(limit: usize, writer: W) -> Self {
+        Self {
+            writer,
+            buf: Vec::with_capacity(limit),
+            limit,
+            error: None,
+        }
+    }
+
+    /// Returns a reference to the underlying writer.
+    #[inline]
+    pub const fn get_ref(&self) -> &W {
+        &self.writer
+    }
+
+    /// Writes any buffered output and flushes the underlying writer.
+    ///
+    /// Returns the first error encountered while rendering, if any.
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.write_buf();
+
+        if let Some(err) = self.error.take() {
+            return Err(err);
+        }
+
+        self.writer.flush()
+    }
+
+    /// Flushes all remaining output and returns the underlying writer.
+    ///
+    /// Returns the first error encountered while rendering, if any.
+    #[inline]
+    pub fn finish(mut self) -> io::Result<W> {
+        self.flush()?;
+        Ok(self.writer)
+    }
+
+    fn write_buf(&mut self) {
+        if self.error.is_none() && !self.buf.is_empty() {
+            self.error = self.writer.write_all(&self.buf).err();
+        }
+
+        self.buf.clear();
+    }
+}
+
+impl<W> Buffer for IoBuffer<W>
+where
+    W: Write,
+{
+    fn push_str(&mut self, s: &str) {
+        if self.buf.len() + s.len() <= self.limit {
+            self.buf.extend_from_slice(s.as_bytes());
+            return;
+        }
+
+        // A write that overflows the buffer follows what is already buffered
+        // straight to the writer, instead of being copied into the buffer.
+        self.write_buf();
+
+        if self.error.is_none() {
+            self.error = self.writer.write_all(s.as_bytes()).err();
+        }
+    }
+}
+
+/// Renders `html` to `writer`, streaming the output in bounded chunks.
+///
+/// This is a convenience wrapper around [`IoBuffer`] using [`DEFAULT_LIMIT`].
+/// The writer is flushed once rendering completes and then returned.
+///
+/// # Example
+///
+/// ```rust
+/// use avosetta::asx;
+///
+/// let rows = 0..3;
+/// let page = asx! {
+///     table {
+///         @for row in rows {
+///             tr { td { @row } }
+///         }
+///     }
+/// };
+///
+/// let output = avosetta::write_io(page, Vec::new()).unwrap();
+///
+/// assert_eq!(
+///     output,
+///     b"<table><tr><td>0</td></tr><tr><td>1</td></tr><tr><td>2</td></tr></table>",
+/// );
+/// ```
+///
+/// Writing to a file, with I/O errors returned to the caller:
+///
+/// ```rust,no_run
+/// use std::{fs::File, io};
+///
+/// use avosetta::asx;
+///
+/// fn save_report(rows: &[&str]) -> io::Result<()> {
+///     let report = asx! {
+///         ul {
+///             @for row in rows {
+///                 li { @row }
+///             }
+///         }
+///     };
+///
+///     let file = File::create("report.html")?;
+///     avosetta::write_io(report, file)?;
+///
+///     Ok(())
+/// }
+/// ```
+#[inline]
+pub fn write_io<T, W>(html: T, writer: W) -> io::Result<W>
+where
+    T: Html<IoBuffer<W>>,
+    W: Write,
+{
+    let mut buf = IoBuffer::new(writer);
+    html.write(&mut buf);
+    buf.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A writer that records each write it receives.
+    #[derive(Default)]
+    struct Writes(Vec<Vec<u8>>);
+
+    impl Write for Writes {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.push(buf.to_vec());
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn buffers_writes_that_fit() {
+        let mut buf = IoBuffer::with_limit(8, Writes::default());
+        buf.push_str("abc");
+        buf.push_str("defgh");
+
+        assert_eq!(buf.finish().unwrap().0, [b"abcdefgh"]);
+    }
+
+    #[test]
+    fn writes_overflowing_chunks_directly() {
+        let mut buf = IoBuffer::with_limit(8, Writes::default());
+        buf.push_str("abcdef");
+        buf.push_str("ghij");
+        buf.push_str("k");
+
+        let writes = buf.finish().unwrap().0;
+        assert_eq!(writes, [&b"abcdef"[..], b"ghij", b"k"]);
+    }
+}
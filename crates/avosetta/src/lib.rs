@@ -5,7 +5,7 @@
 //! `avosetta` provides the [`asx!`] macro, a compact HTML templating syntax that
 //! follows Rust's expression and control-flow conventions. A template expands to
 //! an opaque value implementing [`Html`]; render it by calling [`Html::write`]
-//! with a `String` buffer.
+//! with a `String` or any other [`Buffer`].
 //!
 //! The macro is designed to leave very little work for runtime. Adjacent static
 //! output is combined into larger string writes, and HTML escaping for static
@@ -57,13 +57,38 @@
 //! # };
 //! ```
 //!
-//! Names that are not Rust identifiers, including custom elements, can be string
-//! literals:
+//! Giving children to an HTML void element such as `br`, or ending a known
+//! non-void element such as `div` with a semicolon, is a compile error. An
+//! empty block after a void element, as in `br {}`, is only a warning, and is
+//! written as `<br>`. Inside `svg` and `math`, an element ending with a
+//! semicolon is written as self-closing.
+//!
+//! ```compile_fail
+//! # avosetta::asx! {
+//! br { "not allowed" }
+//! # };
+//! ```
+//!
+//! ```compile_fail
+//! # avosetta::asx! {
+//! div;
+//! # };
+//! ```
+//!
+//! Element and attribute names are identifiers joined by `-`, `:` and `.`, so
+//! custom elements and names such as `hx-get`, `xlink:href` and
+//! `x-on:click.prevent` are written as they are in HTML. Keywords such as `type`,
+//! `for` and `async` are names like any other. Names that cannot be written
+//! this way can be string literals:
 //!
 //! ```rust
 //! # avosetta::asx! {
-//! "x-user-card" {
-//!     div["x-data"="open"] { }
+//! x-user-card[type="card"] {
+//!     div[
+//!         x-data="{ open: false }",
+//!         x-on:click.outside="open = false",
+//!         "@keyup.escape"="open = false",
+//!     ] { }
 //! }
 //! # };
 //! ```
@@ -71,17 +96,106 @@
 //! Template formatting is not copied to the output. Add a string literal when
 //! whitespace is significant.
 //!
+//! Names are not checked against HTML by default. Start a template with
+//! `#![check_names]` to warn about element and attribute names that HTML does
+//! not define, such as `dvi` or `clas`, with a suggestion when a known name is
+//! close. Custom elements, `data-*` and `aria-*` attributes, and the contents of
+//! `svg` and `math` elements are not checked:
+//!
+//! ```rust
+//! # avosetta::asx! {
+//! #![check_names]
+//! div[class="card", data-id=7] {
+//!     x-user-card { }
+//! }
+//! # };
+//! ```
+//!
+//! Each warning is emitted as the use of a deprecated item, so unknown names
+//! fail to compile under `#![deny(deprecated)]`:
+//!
+//! ```compile_fail
+//! # #![deny(deprecated)]
+//! # avosetta::asx! {
+//! #![check_names]
+//! dvi[clas="card"] { }
+//! # };
+//! ```
+//!
+//! Start a template with `#![check_nesting]` to turn content-model violations
+//! into compile errors: `div` inside `p`, `li` outside `ul`, `ol` or `menu`,
+//! elements that are not rows directly inside `table`, nested `a`, `button` or
+//! `form` elements, and similar mistakes that browsers silently repair by moving
+//! elements around. Elements inside `@if`, `@for`, `@match` and the other
+//! control flow are checked against the element around them. Component children
+//! and the contents of `template` are checked on their own, and markup produced
+//! by an expression is not checked:
+//!
+//! ```compile_fail
+//! # avosetta::asx! {
+//! #![check_nesting]
+//! p {
+//!     div { "browsers close the paragraph before this" }
+//! }
+//! # };
+//! ```
+//!
+//! Markup that follows the content model compiles as usual:
+//!
+//! ```rust
+//! # let items = ["First", "Second"];
+//! # avosetta::asx! {
+//! #![check_nesting]
+//! div {
+//!     p { "A list:" }
+//!     ul {
+//!         @for item in items {
+//!             li { a[href="#"] { @item } }
+//!         }
+//!     }
+//! }
+//! # };
+//! ```
+//!
+//! Start a template with `#![check_a11y]` to warn about common accessibility
+//! mistakes: an `img` without `alt`, an `input` that is not inside a `label`,
+//! not named by a `label[for=...]` and has no `aria-label`, an `a` or `button`
+//! without text content, a static `id` used twice, and an `aria-*` attribute
+//! that WAI-ARIA does not define. Expressions are assumed to render text, and
+//! ids in different branches of an `@if` or `@match` may repeat. Write
+//! `#![check_a11y(strict)]` to report the mistakes as errors instead:
+//!
+//! ```rust
+//! # let query = "";
+//! # avosetta::asx! {
+//! #![check_a11y(strict)]
+//! form[role="search"] {
+//!     label[for="query"] { "Search" }
+//!     input[id="query", type="search", value=query];
+//!     button { img[src="/search.svg", alt="Go"]; }
+//! }
+//! # };
+//! ```
+//!
+//! Without the `alt` text, the same button has nothing to describe it:
+//!
+//! ```compile_fail
+//! # avosetta::asx! {
+//! #![check_a11y(strict)]
+//! button { img[src="/search.svg"]; }
+//! # };
+//! ```
+//!
 //! ## Attributes
 //!
-//! Attributes are written in square brackets after an element name. Separate
-//! entries with commas, and use string literals for names that cannot be written
-//! as Rust identifiers:
+//! Attributes are written in square brackets after an element name, separated by
+//! commas:
 //!
 //! ```rust
 //! # let destination = ();
 //! # let label = ();
 //! # avosetta::asx! {
-//! a[href=destination, class="button", "aria-label"=label] {
+//! a[href=destination, class="button", aria-label=label] {
 //!     "Open"
 //! }
 //! # };
@@ -90,7 +204,154 @@
 //! Attribute values are Rust expressions and are rendered through [`Html`].
 //! String values are escaped. Boolean and optional values are useful for
 //! conditional attributes: `false` and `None` omit the attribute, while `true`
-//! emits `name="name"`.
+//! emits `name="name"`. Attributes are rendered in the order they are written.
+//!
+//! An attribute can appear only once per element, since browsers ignore every
+//! occurrence after the first. The exceptions are `class` and `style`: their
+//! values are merged into one attribute, in the position of the first, with
+//! `class` values joined by spaces and `style` values by semicolons. Values that
+//! are `false`, `None`, or empty are skipped:
+//!
+//! ```rust
+//! # use avosetta::{asx, Html};
+//! let selected = Some("selected");
+//! let page = asx! {
+//!     li[class="item", class=selected, style="color: red", style="margin: 0"] { }
+//! };
+//! # let mut output = String::new();
+//! # page.write(&mut output);
+//! # assert_eq!(
+//! #     output,
+//! #     r#"<li class="item selected" style="color: red;margin: 0"></li>"#,
+//! # );
+//! ```
+//!
+//! Repeating any other attribute is a compile error:
+//!
+//! ```compile_fail
+//! # let destination = "/home";
+//! # avosetta::asx! {
+//! a[href="/", href=destination] { "Home" }
+//! # };
+//! ```
+//!
+//! `class:name=condition` adds the class `name` only when `condition` is true.
+//! An element's class literals and toggles are collected into a [`ClassList`],
+//! which skips repeated names and leaves out the attribute when no class is
+//! left:
+//!
+//! ```rust
+//! # use avosetta::{asx, Html};
+//! let active = true;
+//! let disabled = false;
+//! let page = asx! {
+//!     a[class="tab", class:active=active, class:"is-disabled"=disabled] { "Home" }
+//! };
+//! # let mut output = String::new();
+//! # page.write(&mut output);
+//! # assert_eq!(output, r#"<a class="tab active">Home</a>"#);
+//! ```
+//!
+//! `style: { property: value, ... }` writes CSS declarations. Literal values are
+//! written at compile time, while other values build a [`Style`], which escapes
+//! each value and leaves out declarations whose value is `None`. A map can be
+//! merged with other `style` attributes like any other value:
+//!
+//! ```rust
+//! # use avosetta::{asx, Html};
+//! let width = 40;
+//! let color: Option<&str> = None;
+//! let page = asx! {
+//!     div[style: {
+//!         display: "flex",
+//!         margin-top: 0,
+//!         padding: 1.5rem,
+//!         font-size: "1.5em",
+//!         width: format_args!("{width}%"),
+//!         color: color,
+//!     }] { }
+//! };
+//! # let mut output = String::new();
+//! # page.write(&mut output);
+//! # assert_eq!(
+//! #     output,
+//! #     r#"<div style="display:flex;margin-top:0;padding:1.5rem;font-size:1.5em;width:40%"></div>"#,
+//! # );
+//! ```
+//!
+//! A number's suffix is written as its unit, as with `1.5rem`. Rust reads
+//! `1.5em` and `2ex` as malformed exponents, so those units are written as
+//! string literals instead.
+//!
+//! A set of attributes that is only known at runtime, such as a
+//! `Vec<(String, String)>` or a map of `data-*` values, can be spread into an
+//! element with `..`. Each entry is written through [`Attr`], entries whose key
+//! is not a valid attribute name or is an event handler are skipped, and URL
+//! attributes are checked like a [`Url`]; see [`Attrs`]. Repeats between a
+//! spread and the other attributes are not detected:
+//!
+//! ```rust
+//! # use avosetta::{asx, Html};
+//! let extra = vec![("data-block", "hero"), ("onclick", "alert(1)")];
+//! let page = asx! {
+//!     section[class="block", ..extra] { }
+//! };
+//! # let mut output = String::new();
+//! # page.write(&mut output);
+//! # assert_eq!(output, r#"<section class="block" data-block="hero"></section>"#);
+//! ```
+//!
+//! Dynamic values are escaped for the context the attribute's name implies; see
+//! [`EscapeContext`]. URL attributes such as `href` and `src` are
+//! percent-encoded, `on*` event handlers are escaped as the contents of a
+//! JavaScript string literal, and `style` is escaped as a CSS value. String
+//! literals are trusted and only HTML-escaped, so `onclick="toggle()"` is
+//! written as-is. The [`EscapeUrl`], [`EscapeUrlComponent`], [`EscapeJs`], and
+//! [`EscapeCss`] wrappers apply a context explicitly, for example to place a
+//! value in a JavaScript string literal:
+//!
+//! ```rust
+//! # use avosetta::{asx, EscapeJs, Html};
+//! let query = "fish & chips";
+//! let page = asx! {
+//!     script { "const query = '" @EscapeJs(query) "';" }
+//! };
+//! # let mut output = String::new();
+//! # page.write(&mut output);
+//! # assert_eq!(output, r"<script>const query = 'fish \x26 chips';</script>");
+//! ```
+//!
+//! Escaping does not stop a well-formed `javascript:` URL. Wrap URLs that come
+//! from untrusted input in [`Url`], which only renders relative URLs and URLs
+//! with an allowed scheme. Starting a template with `#![check_urls]` makes debug
+//! builds warn wherever a plain string is given to `href`, `src`, `action`, or
+//! `formaction`:
+//!
+//! ```rust
+//! # use avosetta::{asx, Html, Url};
+//! # let website = "https://example.com";
+//! # let page =
+//! asx! {
+//!     #![check_urls]
+//!     a[href=Url::new(website)] { "Website" }
+//! }
+//! # ;
+//! # let mut output = String::new();
+//! # page.write(&mut output);
+//! # assert_eq!(output, r#"<a href="https://example.com">Website</a>"#);
+//! ```
+//!
+//! The warning is reported as a use of a deprecated item, so
+//! `#![deny(deprecated)]` turns it into an error:
+//!
+//! ```compile_fail
+//! # #![deny(deprecated)]
+//! # let website = String::from("javascript:alert(1)");
+//! # avosetta::asx! {
+//! #![check_urls]
+//! a[href=website] { "Website" }
+//! # };
+//! ```
 //!
 //! ## Text and interpolation
 //!
@@ -125,7 +386,38 @@
 //! # };
 //! ```
 //!
-//! Only use [`Raw`] for content whose origin and safety you control.
+//! Only use [`Raw`] for content whose origin and safety you control. To render
+//! user-authored rich text, use [`Sanitize`] instead, which keeps only the
+//! elements and attributes allowed by a [`SanitizePolicy`]:
+//!
+//! ```rust
+//! # use avosetta::{Sanitize, SanitizePolicy};
+//! # let comment = "<b>Hello</b>";
+//! # avosetta::asx! {
+//! div { @Sanitize::new(comment, &SanitizePolicy::FORMATTING) }
+//! # };
+//! ```
+//!
+//! The bodies of `script` and `style` elements are raw text, so entity escaping
+//! does not apply to them. String literals are written unchanged, and dynamic
+//! values are escaped as described for [`RawText`], which stops them from
+//! closing the element early, even together with the literals around them.
+//! Literals that would close the element themselves, such as `"</script>"`
+//! split across several literals, are a compile error. These elements, along
+//! with `textarea` and `title`, cannot contain other elements.
+//!
+//! ```rust
+//! # let query = "</script>";
+//! # avosetta::asx! {
+//! script { "const query = '" @query "';" }
+//! # };
+//! ```
+//!
+//! ```compile_fail
+//! # avosetta::asx! {
+//! script { "document.write('</scr" "ipt>');" }
+//! # };
+//! ```
 //!
 //! ## Rust statements and control flow
 //!
@@ -170,6 +462,214 @@
 //! the surrounding scope can be referenced normally; the generated template
 //! captures them with move semantics.
 //!
+//! ## Components
+//!
+//! A component is a template declared with [`macro@template`], or a struct with
+//! named fields that derives [`Html`](macro@Html). Invoke it with `@` followed
+//! by its name and its props in parentheses. A braced block after the props is
+//! rendered as a nested template and passed as the `children` argument:
+//!
+//! ```rust
+//! # use avosetta::{asx, template, Html};
+//! #[template]
+//! fn card<'a>(title: &'a str, children: impl Html) -> impl Html {
+//!     asx! {
+//!         section {
+//!             h2 { @title }
+//!             @children
+//!         }
+//!     }
+//! }
+//!
+//! # let mut output = String::new();
+//! # avosetta::Html::write(avosetta::asx! {
+//! @Card(title = "Welcome") {
+//!     p { "Thanks for signing up." }
+//! }
+//! # }, &mut output);
+//! # assert_eq!(output, "<section><h2>Welcome</h2><p>Thanks for signing up.</p></section>");
+//! ```
+//!
+//! Props are checked at compile time, so a missing, repeated, or misspelled prop
+//! is an error:
+//!
+//! ```compile_fail
+//! # use avosetta::{asx, template, Html};
+//! #[template]
+//! fn card<'a>(title: &'a str, children: impl Html) -> impl Html {
+//!     asx! { section { h2 { @title } @children } }
+//! }
+//!
+//! # avosetta::asx! {
+//! @Card() {
+//!     p { "The title is missing." }
+//! }
+//! # };
+//! ```
+//!
+//! A component without children is invoked as `@Badge(label = x)`, and one with
+//! only children as `@Panel() { ... }`. Children may contain `@await`, in which
+//! case they are rendered before the component and the surrounding template
+//! becomes asynchronous. Any other struct implementing [`Html`] is interpolated
+//! with a struct expression, as in `@Badge { label: x }`.
+//!
+//! Arguments marked with `#[slot]` are named slots, filled from the children
+//! block with `@slot name { ... }`, and cannot be passed as props. Everything
+//! outside of a slot fill is passed as the children. A missing slot is a
+//! compile error unless it is marked `#[slot(optional)]`, in which case it
+//! renders nothing:
+//!
+//! ```rust
+//! # use avosetta::{asx, template, Html};
+//! #[template]
+//! fn layout<'a>(
+//!     title: &'a str,
+//!     #[slot] sidebar: impl Html,
+//!     #[slot(optional)] footer: impl Html,
+//!     children: impl Html,
+//! ) -> impl Html {
+//!     asx! {
+//!         h1 { @title }
+//!         aside { @sidebar }
+//!         main { @children }
+//!         footer { @footer }
+//!     }
+//! }
+//!
+//! # let mut output = String::new();
+//! # avosetta::Html::write(avosetta::asx! {
+//! @Layout(title = "Settings") {
+//!     @slot sidebar {
+//!         a[href="/"] { "Home" }
+//!     }
+//!
+//!     p { "Nothing to configure yet." }
+//! }
+//! # }, &mut output);
+//! # assert_eq!(
+//! #     output,
+//! #     r#"<h1>Settings</h1><aside><a href="/">Home</a></aside><main><p>Nothing to configure yet.</p></main><footer></footer>"#,
+//! # );
+//! ```
+//!
+//! Slots are declared only by the arguments of a [`macro@template`] function,
+//! since they are passed to it like any other argument; `asx!` itself has no
+//! syntax to declare one. Leaving out a required slot fails to compile:
+//!
+//! ```compile_fail
+//! # use avosetta::{asx, template, Html};
+//! #[template]
+//! fn layout(#[slot] sidebar: impl Html, children: impl Html) -> impl Html {
+//!     asx! { aside { @sidebar } main { @children } }
+//! }
+//!
+//! # avosetta::asx! {
+//! @Layout() {
+//!     p { "The sidebar is missing." }
+//! }
+//! # };
+//! ```
+//!
+//! ## Layouts
+//!
+//! A base layout declared with [`layout!`] marks overridable regions with
+//! `@block name { ... }`, where the braces hold the block's default content. A
+//! template starting with `@extends` renders the layout, overriding only the
+//! blocks it names:
+//!
+//! ```rust
+//! # avosetta::layout! { base; title { @block title {} } main { @block content {} } }
+//! # avosetta::asx! {
+//! @extends base {
+//!     @block title { "About" }
+//!     @block content { p { "Hello!" } }
+//! }
+//! # };
+//! ```
+//!
+//! Blocks are resolved at compile time, so overriding a block that the layout
+//! does not declare, or using `@block` outside of a layout, is an error:
+//!
+//! ```compile_fail
+//! # avosetta::layout! { base; title { @block title {} } main { @block content {} } }
+//! # avosetta::asx! {
+//! @extends base {
+//!     @block sidebar { "There is no sidebar." }
+//! }
+//! # };
+//! ```
+//!
+//! ## Reusable templates
+//!
+//! A template normally consumes the values it captures and can only be written
+//! once. Start the template with `#![reusable]` to produce a value implementing
+//! [`HtmlRef`] instead, which borrows its captures while rendering and can be
+//! written any number of times. Because captures are only borrowed, iterate
+//! over references and match on references inside reusable templates:
+//!
+//! ```rust
+//! use avosetta::{asx, HtmlRef};
+//!
+//! let links = vec!["Home", "About"];
+//! let nav = asx! {
+//!     #![reusable]
+//!     nav {
+//!         @for link in &links {
+//!             a { @link }
+//!         }
+//!     }
+//! };
+//!
+//! let mut output = String::new();
+//! nav.write_ref(&mut output);
+//! nav.write_ref(&mut output);
+//!
+//! assert_eq!(output, "<nav><a>Home</a><a>About</a></nav>".repeat(2));
+//! ```
+//!
+//! Reusable templates also implement [`Html`]. Wrap a borrowed reusable value in
+//! [`ByRef`] to interpolate it into another template more than once.
+//!
+//! ## Asynchronous templates
+//!
+//! `@await` writes the output of a future, or another asynchronous template,
+//! into the template. A template containing `@await` implements [`AsyncHtml`]
+//! instead of [`Html`] and is rendered with [`AsyncHtml::write_async`]:
+//!
+//! ```rust
+//! # async fn load_comments() -> Vec<String> { Vec::new() }
+//! # let _ = async {
+//! # let mut s = String::new();
+//! # avosetta::AsyncHtml::write_async(avosetta::asx! {
+//! section {
+//!     h2 { "Comments" }
+//!     @await load_comments()
+//! }
+//! # }, &mut s).await;
+//! # };
+//! ```
+//!
+//! Code generic over [`AsyncHtml`] cannot require the `write_async` future to be
+//! `Send`. Start the template with `#![send]` to produce a [`SendAsyncHtml`]
+//! value, whose [`SendAsyncHtml::write_send`] future is always `Send` and can be
+//! spawned onto a multi-threaded executor.
+//!
+//! `@defer` marks a subtree for out-of-order streaming through a [`Suspense`].
+//! The page shell is written with a placeholder showing the optional `else`
+//! content, and the deferred subtree is sent later as a separate chunk:
+//!
+//! ```rust
+//! # async fn load_comments() -> Vec<String> { Vec::new() }
+//! # let suspense = &avosetta::Suspense::new();
+//! # avosetta::asx! {
+//! @defer suspense {
+//!     @await load_comments()
+//! } else {
+//!     p { "Loading comments" }
+//! }
+//! # };
+//! ```
+//!
 //! # Rendering values
 //!
 //! [`Html`] is implemented for common text and numeric types, booleans,
@@ -179,13 +679,53 @@
 //!
 //! Implement [`Html`] for application-specific renderable values, or compose
 //! templates by returning the opaque [`Html`] value produced by [`asx!`].
+//! Use [`macro@template`] to give a template a nameable type, or
+//! [`derive(Html)`](macro@Html) to implement [`Html`] for a struct or enum from
+//! a template. Values of different types can be stored together as
+//! `Box<dyn DynHtml>`; see [`DynHtml`].
+//!
+//! # Output buffers
+//!
+//! Rendering is not tied to `String`. Every built-in [`Html`] implementation,
+//! and every template produced by [`asx!`], can write into any [`Buffer`], such
+//! as a `Vec<u8>` or a [`std::fmt::Formatter`]. A template's buffer type is
+//! inferred from where it is written, and is `String` when nothing else
+//! decides it, as in `impl Html` signatures.
+//!
+//! Large documents can be streamed to any [`std::io::Write`] with [`write_io`],
+//! which writes the output in bounded chunks instead of building the whole
+//! document in memory first.
 
 use std::{
-    fmt::{Arguments, Write},
+    borrow::Cow,
+    fmt::{Arguments, Formatter, Write},
+    pin::Pin,
     rc::Rc,
     sync::Arc,
 };
 
+pub use crate::{
+    attrs::{AttrEntry, Attrs},
+    class_list::ClassList,
+    context::{EscapeBuffer, EscapeContext, EscapeCss, EscapeJs, EscapeUrl, EscapeUrlComponent},
+    io::{DEFAULT_LIMIT, IoBuffer, write_io},
+    raw_text::{RawText, RawTextBuffer},
+    sanitize::{Sanitize, SanitizePolicy},
+    style::Style,
+    suspense::{Placeholder, Suspense},
+    url::Url,
+};
+
+mod attrs;
+mod class_list;
+mod context;
+mod io;
+mod raw_text;
+mod sanitize;
+mod style;
+mod suspense;
+mod url;
+
 #[cfg(feature = "macros")]
 #[doc(hidden)]
 pub use avosetta_macros::asx as __asx;
@@ -208,73 +748,784 @@ macro_rules! asx {
     };
 }
 
-/// A value that can append an HTML representation to a string buffer.
+#[cfg(feature = "macros")]
+#[doc(hidden)]
+pub use avosetta_macros::layout as __layout;
+
+/// Declares a base layout that templates can extend with `@extends`.
 ///
-/// Text-oriented implementations should escape HTML-sensitive characters unless
-/// the type explicitly represents trusted, already-rendered markup, as [`Raw`]
-/// does. Implementations may write an empty string.
+/// A layout is an [`asx!`] template containing named blocks, written as
+/// `@block name { ... }`, whose contents are the block's default content. A
+/// template extending the layout overrides only the blocks it cares about, and
+/// every other block keeps its default:
 ///
-/// The trait consumes `self`, allowing template values to own and move captured
-/// data without requiring allocation for an intermediate representation.
-pub trait Html {
-    /// Appends this value's HTML representation to `s`.
-    fn write(self, s: &mut String);
-
-    #[doc(hidden)]
-    #[inline]
-    fn is_none(&self) -> bool {
-        false
-    }
-
-    #[doc(hidden)]
-    #[inline]
-    fn is_false(&self) -> bool {
-        false
-    }
-
-    #[doc(hidden)]
-    #[inline]
-    fn is_true(&self) -> bool {
-        false
-    }
-}
-
-impl Html for () {
-    #[inline]
-    fn write(self, _s: &mut String) {}
+/// ```rust
+/// use avosetta::{asx, layout, Html};
+///
+/// layout! {
+///     base;
+///
+///     html {
+///         head {
+///             title { @block title { "My site" } }
+///         }
+///
+///         body {
+///             main { @block content {} }
+///             footer { @block footer { "Thanks for visiting" } }
+///         }
+///     }
+/// }
+///
+/// let page = asx! {
+///     @extends base {
+///         @block title { "About" }
+///         @block content { p { "Hello!" } }
+///     }
+/// };
+///
+/// let mut output = String::new();
+/// page.write(&mut output);
+///
+/// assert_eq!(
+///     output,
+///     "<html><head><title>About</title></head><body><main><p>Hello!</p></main>\
+///      <footer>Thanks for visiting</footer></body></html>",
+/// );
+/// ```
+///
+/// Blocks are substituted when the extending template is compiled, so the
+/// layout and the overrides form a single template, and static content on
+/// either side of a block is still combined into one write. Overriding a block
+/// that the layout does not declare is a compile error, and so is `@block`
+/// outside of a layout.
+///
+/// The layout is defined as a `macro_rules!` macro with the given name, so it
+/// follows the usual scoping rules for macros. Give it a visibility such as
+/// `pub(crate)` to refer to it by path, as in `@extends crate::layouts::base`.
+/// A `pub` layout is also exported for use by other crates, like a
+/// `#[macro_export]` macro, and keeps its module path. Paths in a `pub` layout
+/// are resolved where it is used, so refer to other layouts of the same crate as
+/// `$crate::layouts::base`. Layouts cannot reference local variables; pass
+/// values in through blocks.
+///
+/// A layout may itself start with `@extends`, declaring new blocks inside the
+/// blocks it overrides. Overrides of blocks it does not declare are passed on
+/// to the layout it extends.
+///
+/// This macro is available when the `macros` crate feature is enabled.
+#[cfg(feature = "macros")]
+#[macro_export]
+macro_rules! layout {
+    ($($tt:tt)*) => {
+        $crate::__layout!{$crate, $($tt)*}
+    };
 }
 
-/// Marks a string-like value as trusted HTML and writes it without escaping.
+/// Declares a named template type from a function.
 ///
-/// `Raw` accepts any value implementing [`AsRef<str>`]. It is useful for
-/// pre-rendered markup, but it bypasses the escaping normally applied to strings.
-/// Never wrap untrusted or user-controlled input in `Raw`.
+/// [`asx!`] returns an opaque type, which cannot be stored in a struct field or a
+/// `static`, or returned from a trait method without boxing. `#[template]`
+/// turns a function returning a template into a struct that implements
+/// [`Html`]. The struct is named after the function in `UpperCamelCase`, its
+/// fields are the function's arguments, and its [`Html::write`] implementation
+/// renders the function body. The function itself is kept as a constructor for
+/// the struct.
+///
+/// The function must be declared to return `impl Html`, and its body may
+/// `return` early as usual. Async, `extern`, and variadic functions and methods
+/// are rejected.
+///
+/// Arguments declared as `impl Html`, or with a generic parameter bounded by
+/// `Html`, may be rendered into any [`Buffer`]. An `impl Html` argument becomes
+/// a type parameter of the struct named after the argument, so a
+/// `children: impl Html` argument adds a `Children` parameter. Such templates
+/// can be invoked as components; see the [crate documentation](crate#components).
+///
+/// Mark an argument with `#[slot]` to declare a named slot, or with
+/// `#[slot(optional)]` to declare a slot that defaults to `()` when a component
+/// invocation does not fill it. Optional slots must have a generic type, such
+/// as `impl Html`.
+///
+/// The struct name can be set with `#[template(name = Ident)]`. Generated code
+/// refers to this crate as `::avosetta`; use `#[template(crate = path)]` when it
+/// is available under another path.
+///
+/// This macro is available when the `macros` crate feature is enabled.
 ///
 /// # Example
 ///
 /// ```rust
-/// use avosetta::{Html, Raw};
+/// use avosetta::{asx, template, Html};
 ///
-/// let mut output = String::new();
+/// #[template]
+/// fn user_card(name: String, posts: u32) -> impl Html {
+///     asx! {
+///         div[class="card"] {
+///             h2 { @name }
+///             p { @posts " posts" }
+///         }
+///     }
+/// }
+///
+/// struct Sidebar {
+///     card: UserCard,
+/// }
+///
+/// let sidebar = Sidebar {
+///     card: user_card("Ada".to_owned(), 3),
+/// };
+///
+/// let mut output = String::new();
+/// sidebar.card.write(&mut output);
+///
+/// assert_eq!(output, r#"<div class="card"><h2>Ada</h2><p>3 posts</p></div>"#);
+/// ```
+#[cfg(feature = "macros")]
+pub use avosetta_macros::template;
+
+/// Derives [`Html`] for a struct or enum from an [`asx!`] template.
+///
+/// On a struct, the template is written in an `#[html(...)]` attribute on the
+/// type. On an enum, each variant carries its own `#[html(...)]` template, and
+/// the derived implementation matches on the variant the way `@match` does,
+/// rendering its template with its own options. The fields are in scope as
+/// bindings: named fields by name, and tuple fields as `_0`, `_1`, and so on.
+///
+/// As with the standard derives, every type parameter of the type is required
+/// to implement [`Html`] for the buffer being written into. A struct with named
+/// fields can also be invoked as a component, with its fields as props; see
+/// the [crate documentation](crate#components).
+///
+/// Generated code refers to this crate as `::avosetta`; add
+/// `#[html(crate = path)]` to the type when it is available under another name.
+/// The path applies to every variant of an enum and cannot be set on a variant.
+///
+/// This macro is available when the `macros` crate feature is enabled.
+///
+/// # Example
+///
+/// ```rust
+/// use avosetta::Html;
+///
+/// #[derive(Html)]
+/// #[html(a[href=url] { @label })]
+/// struct Link {
+///     url: String,
+///     label: &'static str,
+/// }
+///
+/// #[derive(Html)]
+/// enum Status {
+///     #[html(strong { "Ready" })]
+///     Ready,
+///
+///     #[html(span { "Waiting for " @_0 " jobs" })]
+///     Waiting(u32),
+/// }
+///
+/// let mut output = String::new();
+/// Link { url: "/about".to_owned(), label: "About" }.write(&mut output);
+/// Status::Waiting(3).write(&mut output);
+///
+/// assert_eq!(
+///     output,
+///     r#"<a href="/about">About</a><span>Waiting for 3 jobs</span>"#,
+/// );
+/// ```
+#[cfg(feature = "macros")]
+pub use avosetta_macros::Html;
+
+/// An output sink that rendered HTML can be appended to.
+///
+/// [`Html`] values write through this trait, so a template can render directly
+/// into any buffer without an intermediate `String`. It is implemented for
+/// [`String`], `Vec<u8>`, and [`std::fmt::Formatter`], and for `bytes::BytesMut`
+/// when the `bytes` crate feature is enabled.
+///
+/// Implement `Buffer` for application-specific sinks such as pooled buffers.
+/// Only [`Buffer::push_str`] is required.
+///
+/// # Example
+///
+/// ```rust
+/// use avosetta::{asx, Html};
+///
+/// let name = "<Ada>";
+///
+/// let mut output = Vec::new();
+/// asx! { p { "Hello, " @name } }.write(&mut output);
+///
+/// assert_eq!(output, b"<p>Hello, &lt;Ada&gt;</p>");
+/// ```
+pub trait Buffer {
+    /// Appends a string slice to the buffer.
+    fn push_str(&mut self, s: &str);
+
+    /// Appends a single character to the buffer.
+    #[inline]
+    fn push(&mut self, ch: char) {
+        self.push_str(ch.encode_utf8(&mut [0; 4]));
+    }
+
+    /// Hints that at least `additional` more bytes are about to be written.
+    ///
+    /// The default implementation does nothing.
+    #[inline]
+    fn reserve(&mut self, additional: usize) {
+        let _ = additional;
+    }
+
+    /// Appends untrusted text, escaped for the context the buffer writes into.
+    ///
+    /// Strings and characters rendered through [`Html`] are written with this
+    /// method. The default implementation replaces `&`, `<`, `>`, `"`, and `'`
+    /// with HTML entities and appends the result with [`Buffer::push_str`].
+    /// Adapters such as [`RawTextBuffer`] override it to escape text for
+    /// other contexts.
+    fn push_text(&mut self, s: &str) {
+        self.reserve(s.len());
+
+        let mut start = 0;
+
+        for (i, byte) in s.bytes().enumerate() {
+            let entity = match byte {
+                b'&' => "&amp;",
+                b'<' => "&lt;",
+                b'>' => "&gt;",
+                b'"' => "&quot;",
+                b'\'' => "&#39;",
+
+                _ => continue,
+            };
+
+            self.push_str(&s[start..i]);
+            self.push_str(entity);
+            start = i + 1;
+        }
+
+        self.push_str(&s[start..]);
+    }
+}
+
+impl Buffer for String {
+    #[inline]
+    fn push_str(&mut self, s: &str) {
+        String::push_str(self, s);
+    }
+
+    #[inline]
+    fn push(&mut self, ch: char) {
+        String::push(self, ch);
+    }
+
+    #[inline]
+    fn reserve(&mut self, additional: usize) {
+        String::reserve(self, additional);
+    }
+}
+
+impl Buffer for Vec<u8> {
+    #[inline]
+    fn push_str(&mut self, s: &str) {
+        self.extend_from_slice(s.as_bytes());
+    }
+
+    #[inline]
+    fn reserve(&mut self, additional: usize) {
+        Vec::reserve(self, additional);
+    }
+}
+
+/// Writes through the formatter's underlying writer.
+///
+/// [`Buffer`] is infallible, so errors reported by the formatter are discarded.
+impl Buffer for Formatter<'_> {
+    #[inline]
+    fn push_str(&mut self, s: &str) {
+        let _ = self.write_str(s);
+    }
+
+    #[inline]
+    fn push(&mut self, ch: char) {
+        let _ = self.write_char(ch);
+    }
+}
+
+#[cfg(feature = "bytes")]
+impl Buffer for bytes::BytesMut {
+    #[inline]
+    fn push_str(&mut self, s: &str) {
+        self.extend_from_slice(s.as_bytes());
+    }
+
+    #[inline]
+    fn reserve(&mut self, additional: usize) {
+        bytes::BytesMut::reserve(self, additional);
+    }
+}
+
+impl<B> Buffer for &mut B
+where
+    B: Buffer + ?Sized,
+{
+    #[inline]
+    fn push_str(&mut self, s: &str) {
+        (**self).push_str(s);
+    }
+
+    #[inline]
+    fn push(&mut self, ch: char) {
+        (**self).push(ch);
+    }
+
+    #[inline]
+    fn reserve(&mut self, additional: usize) {
+        (**self).reserve(additional);
+    }
+
+    #[inline]
+    fn push_text(&mut self, s: &str) {
+        (**self).push_text(s);
+    }
+}
+
+/// A value that can append an HTML representation to a [`Buffer`].
+///
+/// Text-oriented implementations should escape HTML-sensitive characters unless
+/// the type explicitly represents trusted, already-rendered markup, as [`Raw`]
+/// does. Implementations may write an empty string.
+///
+/// The trait consumes `self`, allowing template values to own and move captured
+/// data without requiring allocation for an intermediate representation.
+///
+/// The buffer type defaults to [`String`]. Implementations that do not depend on
+/// a particular buffer should be generic over `B: Buffer + ?Sized`, so that the
+/// value can be rendered into any sink.
+pub trait Html<B = String>
+where
+    B: Buffer + ?Sized,
+{
+    /// Appends this value's HTML representation to `s`.
+    fn write(self, s: &mut B);
+
+    #[doc(hidden)]
+    #[inline]
+    fn is_none(&self) -> bool {
+        false
+    }
+
+    #[doc(hidden)]
+    #[inline]
+    fn is_false(&self) -> bool {
+        false
+    }
+
+    #[doc(hidden)]
+    #[inline]
+    fn is_true(&self) -> bool {
+        false
+    }
+}
+
+impl<B> Html<B> for ()
+where
+    B: Buffer + ?Sized,
+{
+    #[inline]
+    fn write(self, _s: &mut B) {}
+}
+
+/// A value that can append an HTML representation to a [`Buffer`] by
+/// reference, and therefore be rendered more than once.
+///
+/// [`Html`] consumes `self`, so a template can only be written once. `HtmlRef`
+/// borrows `self` instead. [`asx!`] produces a reusable `HtmlRef` template when
+/// the template starts with the `#![reusable]` attribute; see the
+/// [crate-level syntax reference](crate#reusable-templates).
+///
+/// `HtmlRef` is implemented for the same text, numeric, and wrapper types as
+/// [`Html`], for references and smart pointers to them, and for collections.
+///
+/// # Example
+///
+/// ```rust
+/// use avosetta::{Attr, HtmlRef};
+///
+/// let attr = Attr("title", "5 < 8");
+///
+/// let mut output = String::new();
+/// attr.write_ref(&mut output);
+/// output.push(' ');
+/// attr.write_ref(&mut output);
+///
+/// assert_eq!(output, "title=\"5 &lt; 8\" title=\"5 &lt; 8\"");
+/// ```
+pub trait HtmlRef<B = String>
+where
+    B: Buffer + ?Sized,
+{
+    /// Appends this value's HTML representation to `s`.
+    fn write_ref(&self, s: &mut B);
+
+    #[doc(hidden)]
+    #[inline]
+    fn is_none(&self) -> bool {
+        false
+    }
+
+    #[doc(hidden)]
+    #[inline]
+    fn is_false(&self) -> bool {
+        false
+    }
+
+    #[doc(hidden)]
+    #[inline]
+    fn is_true(&self) -> bool {
+        false
+    }
+}
+
+impl<B> HtmlRef<B> for ()
+where
+    B: Buffer + ?Sized,
+{
+    #[inline]
+    fn write_ref(&self, _s: &mut B) {}
+}
+
+macro_rules! impl_ref_pointer {
+    ($ty:ty) => {
+        impl<T, B> HtmlRef<B> for $ty
+        where
+            T: HtmlRef<B> + ?Sized,
+            B: Buffer + ?Sized,
+        {
+            #[inline]
+            fn write_ref(&self, s: &mut B) {
+                (**self).write_ref(s);
+            }
+
+            #[inline]
+            fn is_none(&self) -> bool {
+                (**self).is_none()
+            }
+
+            #[inline]
+            fn is_false(&self) -> bool {
+                (**self).is_false()
+            }
+
+            #[inline]
+            fn is_true(&self) -> bool {
+                (**self).is_true()
+            }
+        }
+    };
+}
+
+impl_ref_pointer!(&T);
+impl_ref_pointer!(Box<T>);
+impl_ref_pointer!(Rc<T>);
+impl_ref_pointer!(Arc<T>);
+
+/// Renders a borrowed [`HtmlRef`] value through [`Html`].
+///
+/// This allows a reusable value to be interpolated into any template, or passed
+/// anywhere an [`Html`] value is expected, as many times as needed.
+///
+/// # Example
+///
+/// ```rust
+/// use avosetta::{asx, ByRef, Html};
+///
+/// let title = "Report";
+/// let header = asx! {
+///     #![reusable]
+///     h1 { @title }
+/// };
+///
+/// let page = asx! {
+///     @ByRef(&header)
+///     table { }
+///     @ByRef(&header)
+/// };
+///
+/// let mut output = String::new();
+/// page.write(&mut output);
+///
+/// assert_eq!(output, "<h1>Report</h1><table></table><h1>Report</h1>");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ByRef<'a, T>(pub &'a T)
+where
+    T: ?Sized;
+
+impl<T, B> Html<B> for ByRef<'_, T>
+where
+    T: HtmlRef<B> + ?Sized,
+    B: Buffer + ?Sized,
+{
+    #[inline]
+    fn write(self, s: &mut B) {
+        self.0.write_ref(s);
+    }
+
+    #[inline]
+    fn is_none(&self) -> bool {
+        self.0.is_none()
+    }
+
+    #[inline]
+    fn is_false(&self) -> bool {
+        self.0.is_false()
+    }
+
+    #[inline]
+    fn is_true(&self) -> bool {
+        self.0.is_true()
+    }
+}
+
+/// A value that can asynchronously append an HTML representation to a
+/// [`Buffer`].
+///
+/// [`asx!`] produces an `AsyncHtml` value instead of an [`Html`] value when the
+/// template contains an `@await` node. `@await` accepts either an `AsyncHtml`
+/// value or a future whose output implements [`Html`]. Synchronous values are
+/// interpolated into asynchronous templates with `@` as usual.
+///
+/// Any [`IntoFuture`] whose output implements [`Html`] is an `AsyncHtml` value.
+/// Wrap a synchronous value in [`std::future::ready`] to pass it where an
+/// `AsyncHtml` value is expected.
+///
+/// # Example
+///
+/// ```rust
+/// use avosetta::{asx, AsyncHtml};
+///
+/// async fn load_name() -> &'static str {
+///     "<Ada>"
+/// }
+///
+/// # let future = async {
+/// let header = asx! { h1 { "Profile" } };
+/// let greeting = asx! { p { "Hello, " @await load_name() } };
+/// let page = asx! {
+///     main {
+///         @header
+///         @await greeting
+///     }
+/// };
+///
+/// let mut html = String::new();
+/// page.write_async(&mut html).await;
+///
+/// assert_eq!(html, "<main><h1>Profile</h1><p>Hello, &lt;Ada&gt;</p></main>");
+/// # };
+/// # let mut future = std::pin::pin!(future);
+/// # let mut cx = std::task::Context::from_waker(std::task::Waker::noop());
+/// # assert!(future.as_mut().poll(&mut cx).is_ready());
+/// ```
+pub trait AsyncHtml<B = String>
+where
+    B: Buffer + ?Sized,
+{
+    /// Appends this value's HTML representation to `s`, completing once the
+    /// whole value has been written.
+    fn write_async(self, s: &mut B) -> impl Future<Output = ()>;
+}
+
+impl<F, B> AsyncHtml<B> for F
+where
+    F: IntoFuture,
+    F::Output: Html<B>,
+    B: Buffer + ?Sized,
+{
+    #[inline]
+    async fn write_async(self, s: &mut B) {
+        self.await.write(s);
+    }
+}
+
+#[doc(hidden)]
+pub struct __AsyncHtml<F>(pub F);
+
+impl<F, B> AsyncHtml<B> for __AsyncHtml<F>
+where
+    F: AsyncFnOnce(&mut B),
+    B: Buffer + ?Sized,
+{
+    #[inline]
+    fn write_async(self, s: &mut B) -> impl Future<Output = ()> {
+        (self.0)(s)
+    }
+}
+
+/// An [`AsyncHtml`] value whose writing future is [`Send`].
+///
+/// The future returned by [`AsyncHtml::write_async`] is only `Send` where the
+/// value's concrete type is known. Code generic over `AsyncHtml`, such as an
+/// async web framework that spawns handlers onto a multi-threaded executor,
+/// cannot require it to be `Send`, and should accept `SendAsyncHtml` values
+/// instead. Start an asynchronous
+/// template with `#![send]` to produce a `SendAsyncHtml` value instead. The
+/// option has no effect on templates without `@await`. Its `@await` nodes
+/// must accept `SendAsyncHtml` values, and every value it holds across an
+/// `@await` must be `Send`.
+///
+/// Any `Send` [`IntoFuture`] whose future is `Send` and whose output implements
+/// [`Html`] is a `SendAsyncHtml` value.
+///
+/// # Example
+///
+/// ```rust
+/// use avosetta::{asx, SendAsyncHtml};
+///
+/// async fn load_name() -> &'static str {
+///     "Ada"
+/// }
+///
+/// fn spawn<T: Send>(task: T) -> T {
+///     task
+/// }
+///
+/// let page = asx! {
+///     #![send]
+///     p { "Hello, " @await load_name() }
+/// };
+///
+/// let future = spawn(async move {
+///     let mut html = String::new();
+///     page.write_send(&mut html).await;
+///     html
+/// });
+/// # let mut future = std::pin::pin!(future);
+/// # let mut cx = std::task::Context::from_waker(std::task::Waker::noop());
+/// # let std::task::Poll::Ready(html) = future.as_mut().poll(&mut cx) else { panic!() };
+/// # assert_eq!(html, "<p>Hello, Ada</p>");
+/// ```
+pub trait SendAsyncHtml<B = String>: Send
+where
+    B: Buffer + Send + ?Sized,
+{
+    /// Appends this value's HTML representation to `s`, completing once the
+    /// whole value has been written.
+    fn write_send(self, s: &mut B) -> impl Future<Output = ()> + Send;
+}
+
+impl<F, B> SendAsyncHtml<B> for F
+where
+    F: IntoFuture + Send,
+    F::IntoFuture: Send,
+    F::Output: Html<B>,
+    B: Buffer + Send + ?Sized,
+{
+    #[inline]
+    async fn write_send(self, s: &mut B) {
+        self.await.write(s);
+    }
+}
+
+#[doc(hidden)]
+pub type __SendFuture<'a> = Pin<Box<dyn Future<Output = ()> + Send + 'a>>;
+
+#[doc(hidden)]
+pub struct __SendAsyncHtml<F>(pub F);
+
+impl<F, B> SendAsyncHtml<B> for __SendAsyncHtml<F>
+where
+    F: FnOnce(&mut B) -> __SendFuture<'_> + Send,
+    B: Buffer + Send + ?Sized,
+{
+    #[inline]
+    fn write_send(self, s: &mut B) -> impl Future<Output = ()> + Send {
+        (self.0)(s)
+    }
+}
+
+impl<F, B> AsyncHtml<B> for __SendAsyncHtml<F>
+where
+    F: FnOnce(&mut B) -> __SendFuture<'_>,
+    B: Buffer + ?Sized,
+{
+    #[inline]
+    fn write_async(self, s: &mut B) -> impl Future<Output = ()> {
+        (self.0)(s)
+    }
+}
+
+/// Chooses `String` as a template's buffer when nothing else decides it.
+///
+/// Templates pass a reference to the literal `0` alongside their closure, with
+/// this trait linking its type to the buffer's. An integer literal whose type
+/// is not otherwise known is an `i32`, which only accepts `String`, while
+/// `i64` accepts every buffer and is chosen when the template's use requires
+/// a different one.
+#[doc(hidden)]
+pub trait __DefaultBuffer<B>
+where
+    B: Buffer + ?Sized,
+{
+}
+
+impl __DefaultBuffer<String> for i32 {}
+
+impl<B> __DefaultBuffer<B> for i64 where B: Buffer + ?Sized {}
+
+#[doc(hidden)]
+pub struct __Missing;
+
+#[doc(hidden)]
+pub struct __Set<T>(pub T);
+
+/// Marks a string-like value as trusted HTML and writes it without escaping.
+///
+/// `Raw` accepts any value implementing [`AsRef<str>`]. It is useful for
+/// pre-rendered markup, but it bypasses the escaping normally applied to strings.
+/// Never wrap untrusted or user-controlled input in `Raw`.
+///
+/// # Example
+///
+/// ```rust
+/// use avosetta::{Html, Raw};
+///
+/// let mut output = String::new();
 /// Raw("<em>already rendered</em>").write(&mut output);
 /// assert_eq!(output, "<em>already rendered</em>");
 /// ```
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Raw<T>(pub T);
 
-impl<T> Html for Raw<T>
+impl<T, B> Html<B> for Raw<T>
 where
     T: AsRef<str>,
+    B: Buffer + ?Sized,
 {
     #[inline]
-    fn write(self, s: &mut String) {
+    fn write(self, s: &mut B) {
         s.push_str(self.0.as_ref());
     }
 }
 
-impl Html for bool {
+impl<T, B> HtmlRef<B> for Raw<T>
+where
+    T: AsRef<str>,
+    B: Buffer + ?Sized,
+{
     #[inline]
-    fn write(self, s: &mut String) {
+    fn write_ref(&self, s: &mut B) {
+        s.push_str(self.0.as_ref());
+    }
+}
+
+impl<B> Html<B> for bool
+where
+    B: Buffer + ?Sized,
+{
+    #[inline]
+    fn write(self, s: &mut B) {
         if self {
             s.push_str("true");
         } else {
@@ -283,68 +1534,162 @@ impl Html for bool {
     }
 
     #[inline]
-    fn is_false(&self) -> bool {
-        !*self
+    fn is_false(&self) -> bool {
+        !*self
+    }
+
+    #[inline]
+    fn is_true(&self) -> bool {
+        *self
+    }
+}
+
+impl<B> Html<B> for char
+where
+    B: Buffer + ?Sized,
+{
+    #[inline]
+    fn write(self, s: &mut B) {
+        s.push_text(self.encode_utf8(&mut [0; 4]));
+    }
+}
+
+impl<B> HtmlRef<B> for bool
+where
+    B: Buffer + ?Sized,
+{
+    #[inline]
+    fn write_ref(&self, s: &mut B) {
+        (*self).write(s);
+    }
+
+    #[inline]
+    fn is_false(&self) -> bool {
+        !*self
+    }
+
+    #[inline]
+    fn is_true(&self) -> bool {
+        *self
+    }
+}
+
+impl<B> HtmlRef<B> for char
+where
+    B: Buffer + ?Sized,
+{
+    #[inline]
+    fn write_ref(&self, s: &mut B) {
+        (*self).write(s);
+    }
+}
+
+impl<T, B> Html<B> for Option<T>
+where
+    T: Html<B>,
+    B: Buffer + ?Sized,
+{
+    #[inline]
+    fn write(self, s: &mut B) {
+        if let Some(x) = self {
+            x.write(s);
+        }
+    }
+
+    #[inline]
+    fn is_none(&self) -> bool {
+        self.is_none()
+    }
+
+    #[inline]
+    fn is_false(&self) -> bool {
+        self.as_ref().is_some_and(|x| x.is_false())
+    }
+
+    #[inline]
+    fn is_true(&self) -> bool {
+        self.as_ref().is_some_and(|x| x.is_true())
+    }
+}
+
+impl<T, B> HtmlRef<B> for Option<T>
+where
+    T: HtmlRef<B>,
+    B: Buffer + ?Sized,
+{
+    #[inline]
+    fn write_ref(&self, s: &mut B) {
+        if let Some(x) = self {
+            x.write_ref(s);
+        }
+    }
+
+    #[inline]
+    fn is_none(&self) -> bool {
+        self.is_none()
     }
 
     #[inline]
-    fn is_true(&self) -> bool {
-        *self
+    fn is_false(&self) -> bool {
+        self.as_ref().is_some_and(|x| x.is_false())
     }
-}
 
-impl Html for char {
     #[inline]
-    fn write(self, s: &mut String) {
-        match self {
-            '&' => s.push_str("&amp;"),
-            '<' => s.push_str("&lt;"),
-            '>' => s.push_str("&gt;"),
-            '"' => s.push_str("&quot;"),
-            '\'' => s.push_str("&#39;"),
-
-            x => s.push(x),
-        }
+    fn is_true(&self) -> bool {
+        self.as_ref().is_some_and(|x| x.is_true())
     }
 }
 
-impl<T> Html for Option<T>
+impl<T, E, B> Html<B> for Result<T, E>
 where
-    T: Html,
+    T: Html<B>,
+    E: Html<B>,
+    B: Buffer + ?Sized,
 {
     #[inline]
-    fn write(self, s: &mut String) {
-        if let Some(x) = self {
-            x.write(s);
+    fn write(self, s: &mut B) {
+        match self {
+            Ok(x) => x.write(s),
+            Err(x) => x.write(s),
         }
     }
 
     #[inline]
     fn is_none(&self) -> bool {
-        self.is_none()
+        match self {
+            Ok(x) => x.is_none(),
+            Err(x) => x.is_none(),
+        }
     }
 
     #[inline]
     fn is_false(&self) -> bool {
-        self.as_ref().is_some_and(|x| x.is_false())
+        match self {
+            Ok(x) => x.is_false(),
+            Err(x) => x.is_false(),
+        }
     }
 
     #[inline]
     fn is_true(&self) -> bool {
-        self.as_ref().is_some_and(|x| x.is_true())
+        match self {
+            Ok(x) => x.is_true(),
+            Err(x) => x.is_true(),
+        }
     }
 }
 
-impl<T, E> Html for Result<T, E>
+impl<T, E, B> HtmlRef<B> for Result<T, E>
 where
-    T: Html,
-    E: Html,
+    T: HtmlRef<B>,
+    E: HtmlRef<B>,
+    B: Buffer + ?Sized,
 {
     #[inline]
-    fn write(self, s: &mut String) {
+    fn write_ref(&self, s: &mut B) {
         match self {
-            Ok(x) => x.write(s),
-            Err(x) => x.write(s),
+            Ok(x) => x.write_ref(s),
+            Err(x) => x.write_ref(s),
         }
     }
 
@@ -373,22 +1718,24 @@ where
     }
 }
 
-impl<T> Html for &T
+impl<T, B> Html<B> for &T
 where
-    T: Html + Copy,
+    T: Html<B> + Copy,
+    B: Buffer + ?Sized,
 {
     #[inline]
-    fn write(self, s: &mut String) {
+    fn write(self, s: &mut B) {
         (*self).write(s);
     }
 }
 
-impl<T> Html for &[T]
+impl<T, B> Html<B> for &[T]
 where
-    for<'a> &'a T: Html,
+    for<'a> &'a T: Html<B>,
+    B: Buffer + ?Sized,
 {
     #[inline]
-    fn write(self, s: &mut String) {
+    fn write(self, s: &mut B) {
         for x in self {
             x.write(s);
         }
@@ -397,12 +1744,13 @@ where
 
 macro_rules! impl_owned_iter {
     ($ty:ty) => {
-        impl<T> Html for $ty
+        impl<T, B> Html<B> for $ty
         where
-            T: Html,
+            T: Html<B>,
+            B: Buffer + ?Sized,
         {
             #[inline]
-            fn write(self, s: &mut String) {
+            fn write(self, s: &mut B) {
                 for x in self {
                     x.write(s);
                 }
@@ -414,11 +1762,102 @@ macro_rules! impl_owned_iter {
 impl_owned_iter!(Box<[T]>);
 impl_owned_iter!(Vec<T>);
 
-impl Html for Arguments<'_> {
-    fn write(self, s: &mut String) {
-        struct Writer<'a>(&'a mut String);
+impl<T, B> HtmlRef<B> for [T]
+where
+    T: HtmlRef<B>,
+    B: Buffer + ?Sized,
+{
+    #[inline]
+    fn write_ref(&self, s: &mut B) {
+        for x in self {
+            x.write_ref(s);
+        }
+    }
+}
+
+impl<T, B> HtmlRef<B> for Vec<T>
+where
+    T: HtmlRef<B>,
+    B: Buffer + ?Sized,
+{
+    #[inline]
+    fn write_ref(&self, s: &mut B) {
+        self.as_slice().write_ref(s);
+    }
+}
+
+/// An object-safe companion to [`Html`].
+///
+/// [`Html::write`] consumes `self`, so `Html` cannot be used as a trait object.
+/// `DynHtml` is implemented for every [`Html`] value and writes through a
+/// `Box<Self>` instead, which allows values of different types to be stored
+/// together as `Box<dyn DynHtml>`. The boxed trait objects implement [`Html`],
+/// as do collections of them.
+///
+/// # Example
+///
+/// ```rust
+/// use avosetta::{asx, DynHtml, Html};
+///
+/// let widgets: Vec<Box<dyn DynHtml>> = vec![
+///     Box::new(asx! { h1 { "Dashboard" } }),
+///     Box::new("<unread>"),
+///     Box::new(42),
+/// ];
+///
+/// let mut output = String::new();
+/// widgets.write(&mut output);
+///
+/// assert_eq!(output, "<h1>Dashboard</h1>&lt;unread&gt;42");
+/// ```
+pub trait DynHtml<B = String>
+where
+    B: Buffer + ?Sized,
+{
+    /// Appends this boxed value's HTML representation to `s`.
+    fn write_boxed(self: Box<Self>, s: &mut B);
+}
+
+impl<T, B> DynHtml<B> for T
+where
+    T: Html<B>,
+    B: Buffer + ?Sized,
+{
+    #[inline]
+    fn write_boxed(self: Box<Self>, s: &mut B) {
+        (*self).write(s);
+    }
+}
+
+macro_rules! impl_dyn {
+    ($ty:ty) => {
+        impl<B> Html<B> for $ty
+        where
+            B: Buffer + ?Sized,
+        {
+            #[inline]
+            fn write(self, s: &mut B) {
+                self.write_boxed(s);
+            }
+        }
+    };
+}
+
+impl_dyn!(Box<dyn DynHtml<B> + '_>);
+impl_dyn!(Box<dyn DynHtml<B> + Send + '_>);
+impl_dyn!(Box<dyn DynHtml<B> + Send + Sync + '_>);
 
-        impl Write for Writer<'_> {
+impl<B> Html<B> for Arguments<'_>
+where
+    B: Buffer + ?Sized,
+{
+    fn write(self, s: &mut B) {
+        struct Writer<'a, B: ?Sized>(&'a mut B);
+
+        impl<B> Write for Writer<'_, B>
+        where
+            B: Buffer + ?Sized,
+        {
             #[inline]
             fn write_str(&mut self, s: &str) -> std::fmt::Result {
                 s.write(self.0);
@@ -442,11 +1881,23 @@ impl Html for Arguments<'_> {
     }
 }
 
+impl<B> HtmlRef<B> for Arguments<'_>
+where
+    B: Buffer + ?Sized,
+{
+    #[inline]
+    fn write_ref(&self, s: &mut B) {
+        (*self).write(s);
+    }
+}
+
 /// Escapes a string-like value for safe insertion into HTML text or an
 /// attribute value.
 ///
-/// The characters `&`, `<`, `>`, `"`, and `'` are replaced with HTML entities.
-/// String types already use this behavior through their [`Html`]
+/// The characters `&`, `<`, `>`, `"`, and `'` are replaced with HTML entities,
+/// unless the buffer escapes text for another context; see
+/// [`Buffer::push_text`]. String types already use this behavior through their
+/// [`Html`]
 /// implementations; this wrapper is useful when writing generic code over
 /// [`AsRef<str>`] values.
 ///
@@ -462,24 +1913,25 @@ impl Html for Arguments<'_> {
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Escape<T>(pub T);
 
-impl<T> Html for Escape<T>
+impl<T, B> Html<B> for Escape<T>
 where
     T: AsRef<str>,
+    B: Buffer + ?Sized,
 {
-    fn write(self, s: &mut String) {
-        s.reserve(self.0.as_ref().len());
-
-        for x in self.0.as_ref().chars() {
-            match x {
-                '&' => s.push_str("&amp;"),
-                '<' => s.push_str("&lt;"),
-                '>' => s.push_str("&gt;"),
-                '"' => s.push_str("&quot;"),
-                '\'' => s.push_str("&#39;"),
-
-                x => s.push(x),
-            }
-        }
+    #[inline]
+    fn write(self, s: &mut B) {
+        s.push_text(self.0.as_ref());
+    }
+}
+
+impl<T, B> HtmlRef<B> for Escape<T>
+where
+    T: AsRef<str>,
+    B: Buffer + ?Sized,
+{
+    #[inline]
+    fn write_ref(&self, s: &mut B) {
+        Escape(self.0.as_ref()).write(s);
     }
 }
 
@@ -490,9 +1942,12 @@ where
 /// `key="key"`; `false` and [`Option::None`] omit the attribute; every other value
 /// produces `key="value"`.
 ///
-/// Attribute values that use the standard string [`Html`] implementations are
-/// HTML-escaped. As with any direct [`Html`] implementation, using [`Raw`] as a
-/// value bypasses escaping.
+/// Text in the value is escaped for the [`EscapeContext`] chosen from the key
+/// by [`EscapeContext::for_attribute`]: URL attributes such as `href` are
+/// percent-encoded, `on*` event handlers are escaped as the contents of a
+/// JavaScript string literal, `style` is escaped as a CSS value, and other
+/// attributes are HTML-escaped. As with any direct [`Html`] implementation,
+/// using [`Raw`] as a value bypasses escaping.
 ///
 /// # Example
 ///
@@ -501,43 +1956,348 @@ where
 ///
 /// let mut output = String::new();
 /// Attr("title", "5 < 8").write(&mut output);
-/// assert_eq!(output, "title=\"5 &lt; 8\"");
+/// Attr("href", "/search?q=a b").write(&mut output);
+/// assert_eq!(output, "title=\"5 &lt; 8\"href=\"/search?q=a%20b\"");
 /// ```
-
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Attr<K, V>(pub K, pub V);
 
-impl<K, V> Html for Attr<K, V>
+impl<K, V, B> Html<B> for Attr<K, V>
+where
+    K: for<'a> Html<__AttrName<'a, B>>,
+    V: for<'a> Html<EscapeBuffer<'a, B>>,
+    B: Buffer + ?Sized,
+{
+    fn write(self, s: &mut B) {
+        let Self(key, value) = self;
+
+        if value.is_none() || value.is_false() {
+            return;
+        }
+
+        // The key is written straight to the buffer, which keeps a copy of it
+        // to choose the value's context and to repeat it for `true` values.
+        let mut name = __AttrName::new(s);
+        key.write(&mut name);
+
+        let __AttrName {
+            inner: s,
+            short,
+            len,
+            long,
+        } = name;
+
+        let name = match long {
+            Some(ref x) => x.as_str(),
+            // Only whole strings are copied, so the bytes are always UTF-8.
+            None => std::str::from_utf8(&short[..len]).unwrap_or_default(),
+        };
+
+        s.push_str("=\"");
+
+        if value.is_true() {
+            s.push_str(name);
+        } else {
+            let context = EscapeContext::for_attribute(name);
+            value.write(&mut EscapeBuffer::new(s, context));
+        }
+
+        s.push('\"');
+    }
+}
+
+impl<K, V, B> HtmlRef<B> for Attr<K, V>
+where
+    K: for<'a> HtmlRef<__AttrName<'a, B>>,
+    V: for<'a> HtmlRef<EscapeBuffer<'a, B>>,
+    B: Buffer + ?Sized,
+{
+    #[inline]
+    fn write_ref(&self, s: &mut B) {
+        Attr(ByRef(&self.0), ByRef(&self.1)).write(s);
+    }
+}
+
+/// A buffer that writes the key of an [`Attr`] and keeps a copy of the markup
+/// it writes. Keys of up to 32 bytes are copied without allocating.
+#[doc(hidden)]
+pub struct __AttrName<'a, B>
+where
+    B: Buffer + ?Sized,
+{
+    inner: &'a mut B,
+    short: [u8; 32],
+    len: usize,
+    long: Option<String>,
+}
+
+impl<'a, B> __AttrName<'a, B>
+where
+    B: Buffer + ?Sized,
+{
+    #[inline]
+    fn new(inner: &'a mut B) -> Self {
+        Self {
+            inner,
+            short: [0; 32],
+            len: 0,
+            long: None,
+        }
+    }
+}
+
+impl<B> Buffer for __AttrName<'_, B>
+where
+    B: Buffer + ?Sized,
+{
+    fn push_str(&mut self, s: &str) {
+        self.inner.push_str(s);
+
+        if let Some(long) = &mut self.long {
+            long.push_str(s);
+        } else if let Some(short) = self.short.get_mut(self.len..self.len + s.len()) {
+            short.copy_from_slice(s.as_bytes());
+            self.len += s.len();
+        } else {
+            let mut long = String::from_utf8_lossy(&self.short[..self.len]).into_owned();
+            long.push_str(s);
+            self.long = Some(long);
+        }
+    }
+
+    #[inline]
+    fn reserve(&mut self, additional: usize) {
+        self.inner.reserve(additional);
+    }
+}
+
+/// An [`Attr`] whose context has already been chosen, written by templates
+/// that know the attribute name at compile time.
+#[doc(hidden)]
+pub struct __Attr<K, V>(pub K, pub V, pub EscapeContext);
+
+impl<K, V, B> Html<B> for __Attr<K, V>
 where
-    K: Html,
-    V: Html,
+    K: AsRef<str>,
+    V: for<'a> Html<EscapeBuffer<'a, B>>,
+    B: Buffer + ?Sized,
 {
-    fn write(self, s: &mut String) {
-        if self.1.is_true() {
-            let start = s.len();
-            self.0.write(s);
-            let end = s.len();
+    fn write(self, s: &mut B) {
+        let Self(key, value, context) = self;
 
+        if value.is_true() {
+            s.push_text(key.as_ref());
             s.push_str("=\"");
-            s.extend_from_within(start..end);
+            s.push_text(key.as_ref());
             s.push('\"');
-        } else if !self.1.is_none() && !self.1.is_false() {
-            self.0.write(s);
+        } else if !value.is_none() && !value.is_false() {
+            s.push_text(key.as_ref());
             s.push_str("=\"");
-            self.1.write(s);
+            value.write(&mut EscapeBuffer::new(s, context));
             s.push('\"');
         }
     }
 }
 
+impl<K, V, B> HtmlRef<B> for __Attr<K, V>
+where
+    K: AsRef<str>,
+    V: for<'a> HtmlRef<EscapeBuffer<'a, B>>,
+    B: Buffer + ?Sized,
+{
+    #[inline]
+    fn write_ref(&self, s: &mut B) {
+        __Attr(self.0.as_ref(), ByRef(&self.1), self.2).write(s);
+    }
+}
+
+/// Attribute values joined with a separator, written by templates that repeat
+/// a mergeable attribute such as `class` or `style`.
+///
+/// Values that are `None` or `false`, or that write nothing, are skipped along
+/// with their separator, and the join as a whole is `None` when every value is
+/// `None` or `false`.
+#[doc(hidden)]
+pub struct __Join<A, Z>(pub &'static str, pub A, pub Z);
+
+impl<A, Z, B> Html<B> for __Join<A, Z>
+where
+    A: for<'a> Html<__Separated<'a, B>>,
+    Z: for<'a> Html<__Separated<'a, B>>,
+    B: Buffer + ?Sized,
+{
+    fn write(self, s: &mut B) {
+        let Self(separator, a, z) = self;
+        let mut written = false;
+
+        if !a.is_none() && !a.is_false() {
+            let mut s = __Separated::new(s, "");
+            a.write(&mut s);
+            written = s.written;
+        }
+
+        if !z.is_none() && !z.is_false() {
+            z.write(&mut __Separated::new(
+                s,
+                if written { separator } else { "" },
+            ));
+        }
+    }
+
+    #[inline]
+    fn is_none(&self) -> bool {
+        (self.1.is_none() || self.1.is_false()) && (self.2.is_none() || self.2.is_false())
+    }
+}
+
+impl<A, Z, B> HtmlRef<B> for __Join<A, Z>
+where
+    A: for<'a> HtmlRef<__Separated<'a, B>>,
+    Z: for<'a> HtmlRef<__Separated<'a, B>>,
+    B: Buffer + ?Sized,
+{
+    #[inline]
+    fn write_ref(&self, s: &mut B) {
+        __Join(self.0, ByRef(&self.1), ByRef(&self.2)).write(s);
+    }
+
+    #[inline]
+    fn is_none(&self) -> bool {
+        (self.1.is_none() || self.1.is_false()) && (self.2.is_none() || self.2.is_false())
+    }
+}
+
+/// A buffer that writes a separator before the first non-empty write, so that
+/// [`__Join`] leaves out the separator before a value that writes nothing.
+#[doc(hidden)]
+pub struct __Separated<'a, B>
+where
+    B: Buffer + ?Sized,
+{
+    inner: &'a mut B,
+    separator: &'static str,
+    written: bool,
+}
+
+impl<'a, B> __Separated<'a, B>
+where
+    B: Buffer + ?Sized,
+{
+    #[inline]
+    fn new(inner: &'a mut B, separator: &'static str) -> Self {
+        Self {
+            inner,
+            separator,
+            written: false,
+        }
+    }
+
+    #[inline]
+    fn start(&mut self) {
+        if !self.written {
+            self.inner.push_str(self.separator);
+            self.written = true;
+        }
+    }
+}
+
+impl<B> Buffer for __Separated<'_, B>
+where
+    B: Buffer + ?Sized,
+{
+    #[inline]
+    fn push_str(&mut self, s: &str) {
+        if !s.is_empty() {
+            self.start();
+            self.inner.push_str(s);
+        }
+    }
+
+    #[inline]
+    fn push(&mut self, ch: char) {
+        self.start();
+        self.inner.push(ch);
+    }
+
+    #[inline]
+    fn reserve(&mut self, additional: usize) {
+        self.inner.reserve(additional);
+    }
+
+    #[inline]
+    fn push_text(&mut self, s: &str) {
+        if !s.is_empty() {
+            self.start();
+            self.inner.push_text(s);
+        }
+    }
+}
+
+/// Flags plain strings written to URL attributes by templates using
+/// `#![check_urls]`.
+///
+/// The inherent `check` methods below are only found for plain string types,
+/// and are deprecated so that calling them produces a warning; every other
+/// type falls back to [`__UrlCheckAny::check`].
+#[doc(hidden)]
+pub struct __UrlCheck<'a, T: ?Sized>(pub &'a T);
+
+#[doc(hidden)]
+pub trait __UrlCheckAny {
+    #[inline]
+    fn check(&self) {}
+}
+
+impl<T> __UrlCheckAny for __UrlCheck<'_, T> where T: ?Sized {}
+
+macro_rules! impl_url_check {
+    ($($ty:ty),*) => {
+        $(
+            impl __UrlCheck<'_, $ty> {
+                #[deprecated(
+                    note = "this URL attribute is a plain string, so its scheme is not checked; \
+                            wrap it in `avosetta::Url` to block `javascript:` and other \
+                            dangerous URLs"
+                )]
+                #[inline]
+                pub fn check(&self) {}
+            }
+        )*
+    };
+}
+
+impl_url_check!(
+    &str,
+    &&str,
+    String,
+    &String,
+    Box<str>,
+    Rc<str>,
+    Arc<str>,
+    Cow<'_, str>
+);
+
 macro_rules! impl_integer {
     ($ty:ty) => {
-        impl Html for $ty {
+        impl<B> Html<B> for $ty
+        where
+            B: Buffer + ?Sized,
+        {
             #[inline]
-            fn write(self, s: &mut String) {
+            fn write(self, s: &mut B) {
                 s.push_str(itoa::Buffer::new().format(self));
             }
         }
+
+        impl<B> HtmlRef<B> for $ty
+        where
+            B: Buffer + ?Sized,
+        {
+            #[inline]
+            fn write_ref(&self, s: &mut B) {
+                (*self).write(s);
+            }
+        }
     };
 }
 
@@ -561,12 +2321,25 @@ impl_integer!(i128);
 
 macro_rules! impl_float {
     ($ty:ty) => {
-        impl Html for $ty {
+        impl<B> Html<B> for $ty
+        where
+            B: Buffer + ?Sized,
+        {
             #[inline]
-            fn write(self, s: &mut String) {
+            fn write(self, s: &mut B) {
                 s.push_str(ryu::Buffer::new().format(self));
             }
         }
+
+        impl<B> HtmlRef<B> for $ty
+        where
+            B: Buffer + ?Sized,
+        {
+            #[inline]
+            fn write_ref(&self, s: &mut B) {
+                (*self).write(s);
+            }
+        }
     };
 }
 
@@ -575,9 +2348,12 @@ impl_float!(f64);
 
 macro_rules! impl_string {
     ($ty:ty) => {
-        impl Html for $ty {
+        impl<B> Html<B> for $ty
+        where
+            B: Buffer + ?Sized,
+        {
             #[inline]
-            fn write(self, s: &mut String) {
+            fn write(self, s: &mut B) {
                 Escape(self).write(s);
             }
         }
@@ -590,1965 +2366,27 @@ impl_string!(Box<str>);
 impl_string!(Rc<str>);
 impl_string!(Arc<str>);
 
-#[allow(non_camel_case_types)]
+impl<B> HtmlRef<B> for str
+where
+    B: Buffer + ?Sized,
+{
+    #[inline]
+    fn write_ref(&self, s: &mut B) {
+        Escape(self).write(s);
+    }
+}
+
+impl<B> HtmlRef<B> for String
+where
+    B: Buffer + ?Sized,
+{
+    #[inline]
+    fn write_ref(&self, s: &mut B) {
+        Escape(self).write(s);
+    }
+}
+
 #[doc(hidden)]
-#[cfg(feature = "macros")]
 pub mod __completion {
-    pub mod elements {
-        /// The document’s root element; every other HTML element belongs beneath it.
-        ///
-        /// HTML tag: `<html>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/html)
-        pub struct html;
-
-        /// Sets the base URL used to resolve relative URLs in the document.
-        ///
-        /// HTML tag: `<base>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/base)
-        pub struct base;
-
-        /// Holds machine-readable document metadata such as the title, styles, and scripts.
-        ///
-        /// HTML tag: `<head>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/head)
-        pub struct head;
-
-        /// Declares a relationship between the document and an external resource.
-        ///
-        /// HTML tag: `<link>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/link)
-        pub struct link;
-
-        /// Provides document metadata that is not expressed by another metadata element.
-        ///
-        /// HTML tag: `<meta>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/meta)
-        pub struct meta;
-
-        /// Contains CSS rules that apply to the document or part of it.
-        ///
-        /// HTML tag: `<style>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/style)
-        pub struct style;
-
-        /// Supplies the document title shown in browser tabs and similar user interfaces.
-        ///
-        /// HTML tag: `<title>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/title)
-        pub struct title;
-
-        /// Contains the visible and interactive content of an HTML document.
-        ///
-        /// HTML tag: `<body>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/body)
-        pub struct body;
-
-        /// Provides contact information for a person, group, or organization.
-        ///
-        /// HTML tag: `<address>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/address)
-        pub struct address;
-
-        /// Marks a self-contained composition that can stand alone or be reused independently.
-        ///
-        /// HTML tag: `<article>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/article)
-        pub struct article;
-
-        /// Contains content only indirectly connected to the surrounding primary content.
-        ///
-        /// HTML tag: `<aside>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/aside)
-        pub struct aside;
-
-        /// Defines footer content for its nearest sectioning ancestor or the page.
-        ///
-        /// HTML tag: `<footer>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/footer)
-        pub struct footer;
-
-        /// Groups introductory material or navigational aids for a page or section.
-        ///
-        /// HTML tag: `<header>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/header)
-        pub struct header;
-
-        /// Defines a level-one section heading, the highest heading rank.
-        ///
-        /// HTML tag: `<h1>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/Heading_Elements)
-        pub struct h1;
-
-        /// Defines a level-two section heading.
-        ///
-        /// HTML tag: `<h2>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/Heading_Elements)
-        pub struct h2;
-
-        /// Defines a level-three section heading.
-        ///
-        /// HTML tag: `<h3>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/Heading_Elements)
-        pub struct h3;
-
-        /// Defines a level-four section heading.
-        ///
-        /// HTML tag: `<h4>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/Heading_Elements)
-        pub struct h4;
-
-        /// Defines a level-five section heading.
-        ///
-        /// HTML tag: `<h5>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/Heading_Elements)
-        pub struct h5;
-
-        /// Defines a level-six section heading, the lowest heading rank.
-        ///
-        /// HTML tag: `<h6>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/Heading_Elements)
-        pub struct h6;
-
-        /// Groups a heading with secondary heading-related content such as a subtitle or tagline.
-        ///
-        /// HTML tag: `<hgroup>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/hgroup)
-        pub struct hgroup;
-
-        /// Identifies the body’s dominant content or central application functionality.
-        ///
-        /// HTML tag: `<main>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/main)
-        pub struct main;
-
-        /// Marks a section whose main purpose is providing navigation links.
-        ///
-        /// HTML tag: `<nav>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/nav)
-        pub struct nav;
-
-        /// Represents a standalone section when no more specific semantic element applies.
-        ///
-        /// HTML tag: `<section>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/section)
-        pub struct section;
-
-        /// Contains controls or content used to perform searching or filtering.
-        ///
-        /// HTML tag: `<search>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/search)
-        pub struct search;
-
-        /// Represents an extended quotation, optionally identifying its source.
-        ///
-        /// HTML tag: `<blockquote>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/blockquote)
-        pub struct blockquote;
-
-        /// Provides the description, definition, or value associated with a preceding term.
-        ///
-        /// HTML tag: `<dd>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/dd)
-        pub struct dd;
-
-        /// A generic block container with no inherent semantic meaning.
-        ///
-        /// HTML tag: `<div>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/div)
-        pub struct div;
-
-        /// Contains groups of terms and their corresponding descriptions or values.
-        ///
-        /// HTML tag: `<dl>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/dl)
-        pub struct dl;
-
-        /// Identifies a term or name within a description list.
-        ///
-        /// HTML tag: `<dt>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/dt)
-        pub struct dt;
-
-        /// Provides a caption or legend for its containing figure.
-        ///
-        /// HTML tag: `<figcaption>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/figcaption)
-        pub struct figcaption;
-
-        /// Wraps self-contained content that may include an associated caption.
-        ///
-        /// HTML tag: `<figure>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/figure)
-        pub struct figure;
-
-        /// Marks a thematic break between paragraph-level topics or scenes.
-        ///
-        /// HTML tag: `<hr>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/hr)
-        pub struct hr;
-
-        /// Represents one item within an ordered, unordered, or menu list.
-        ///
-        /// HTML tag: `<li>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/li)
-        pub struct li;
-
-        /// Represents an unordered list of commands or other items.
-        ///
-        /// HTML tag: `<menu>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/menu)
-        pub struct menu;
-
-        /// Contains an ordered sequence of list items.
-        ///
-        /// HTML tag: `<ol>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/ol)
-        pub struct ol;
-
-        /// Represents a paragraph or another cohesive grouping of related phrasing content.
-        ///
-        /// HTML tag: `<p>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/p)
-        pub struct p;
-
-        /// Preserves and displays text formatting and whitespace as written in the source.
-        ///
-        /// HTML tag: `<pre>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/pre)
-        pub struct pre;
-
-        /// Contains an unordered collection of list items.
-        ///
-        /// HTML tag: `<ul>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/ul)
-        pub struct ul;
-
-        /// Creates a hyperlink when supplied with an `href` destination.
-        ///
-        /// HTML tag: `<a>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/a)
-        pub struct a;
-
-        /// Marks an abbreviation or acronym.
-        ///
-        /// HTML tag: `<abbr>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/abbr)
-        pub struct abbr;
-
-        /// Draws attention to text without assigning it additional importance.
-        ///
-        /// HTML tag: `<b>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/b)
-        pub struct b;
-
-        /// Isolates contained text from the bidirectional directionality of surrounding text.
-        ///
-        /// HTML tag: `<bdi>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/bdi)
-        pub struct bdi;
-
-        /// Overrides the text direction used to render its contents.
-        ///
-        /// HTML tag: `<bdo>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/bdo)
-        pub struct bdo;
-
-        /// Inserts a meaningful line break within text.
-        ///
-        /// HTML tag: `<br>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/br)
-        pub struct br;
-
-        /// Marks the title of a creative work.
-        ///
-        /// HTML tag: `<cite>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/cite)
-        pub struct cite;
-
-        /// Identifies a short fragment of computer code.
-        ///
-        /// HTML tag: `<code>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/code)
-        pub struct code;
-
-        /// Associates displayed content with a machine-readable value.
-        ///
-        /// HTML tag: `<data>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/data)
-        pub struct data;
-
-        /// Marks the term currently being defined by its surrounding context.
-        ///
-        /// HTML tag: `<dfn>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/dfn)
-        pub struct dfn;
-
-        /// Applies stress emphasis, with nesting indicating stronger emphasis.
-        ///
-        /// HTML tag: `<em>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/em)
-        pub struct em;
-
-        /// Sets off text with an alternate voice, mood, taxonomy, idiom, or technical meaning.
-        ///
-        /// HTML tag: `<i>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/i)
-        pub struct i;
-
-        /// Represents user input from a keyboard, voice interface, or other input device.
-        ///
-        /// HTML tag: `<kbd>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/kbd)
-        pub struct kbd;
-
-        /// Highlights text because it is relevant to the current context.
-        ///
-        /// HTML tag: `<mark>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/mark)
-        pub struct mark;
-
-        /// Represents a short inline quotation.
-        ///
-        /// HTML tag: `<q>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/q)
-        pub struct q;
-
-        /// Provides fallback punctuation around ruby annotations for unsupported renderers.
-        ///
-        /// HTML tag: `<rp>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/rp)
-        pub struct rp;
-
-        /// Contains pronunciation, translation, or transliteration text for a ruby annotation.
-        ///
-        /// HTML tag: `<rt>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/rt)
-        pub struct rt;
-
-        /// Groups base text with small annotations commonly used in East Asian typography.
-        ///
-        /// HTML tag: `<ruby>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/ruby)
-        pub struct ruby;
-
-        /// Marks content that is no longer accurate or relevant, commonly rendered struck through.
-        ///
-        /// HTML tag: `<s>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/s)
-        pub struct s;
-
-        /// Represents sample or quoted output from a computer program.
-        ///
-        /// HTML tag: `<samp>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/samp)
-        pub struct samp;
-
-        /// Marks side comments or small-print information such as legal or copyright text.
-        ///
-        /// HTML tag: `<small>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/small)
-        pub struct small;
-
-        /// A generic inline container with no inherent semantic meaning.
-        ///
-        /// HTML tag: `<span>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/span)
-        pub struct span;
-
-        /// Indicates strong importance, seriousness, or urgency.
-        ///
-        /// HTML tag: `<strong>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/strong)
-        pub struct strong;
-
-        /// Displays inline text as typographic subscript.
-        ///
-        /// HTML tag: `<sub>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/sub)
-        pub struct sub;
-
-        /// Displays inline text as typographic superscript.
-        ///
-        /// HTML tag: `<sup>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/sup)
-        pub struct sup;
-
-        /// Represents a date, time, duration, or other specific temporal value.
-        ///
-        /// HTML tag: `<time>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/time)
-        pub struct time;
-
-        /// Marks text with a non-textual annotation, typically rendered with an underline.
-        ///
-        /// HTML tag: `<u>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/u)
-        pub struct u;
-
-        /// Represents a variable name in mathematics or programming.
-        ///
-        /// HTML tag: `<var>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/var)
-        pub struct var;
-
-        /// Marks a position where the browser may optionally break a line.
-        ///
-        /// HTML tag: `<wbr>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/wbr)
-        pub struct wbr;
-
-        /// Defines a clickable region within an image map.
-        ///
-        /// HTML tag: `<area>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/area)
-        pub struct area;
-
-        /// Embeds sound content or a streamable audio source.
-        ///
-        /// HTML tag: `<audio>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/audio)
-        pub struct audio;
-
-        /// Embeds an image in the document.
-        ///
-        /// HTML tag: `<img>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/img)
-        pub struct img;
-
-        /// Defines an image map containing clickable areas.
-        ///
-        /// HTML tag: `<map>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/map)
-        pub struct map;
-
-        /// Adds timed text or time-based data, such as subtitles, to media.
-        ///
-        /// HTML tag: `<track>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/track)
-        pub struct track;
-
-        /// Embeds a media player for video and, when appropriate, audio.
-        ///
-        /// HTML tag: `<video>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/video)
-        pub struct video;
-
-        /// Inserts externally provided content at a specific point in the document.
-        ///
-        /// HTML tag: `<embed>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/embed)
-        pub struct embed;
-
-        /// Creates a privacy-focused nested browsing context similar to an iframe.
-        ///
-        /// HTML tag: `<fencedframe>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/fencedframe)
-        pub struct fencedframe;
-
-        /// Embeds another HTML page as a nested browsing context.
-        ///
-        /// HTML tag: `<iframe>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/iframe)
-        pub struct iframe;
-
-        /// Embeds an external resource handled as an image, browsing context, or plugin resource.
-        ///
-        /// HTML tag: `<object>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/object)
-        pub struct object;
-
-        /// Offers alternate image sources for different display or device conditions.
-        ///
-        /// HTML tag: `<picture>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/picture)
-        pub struct picture;
-
-        /// Provides an alternative media resource for picture, audio, or video.
-        ///
-        /// HTML tag: `<source>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/source)
-        pub struct source;
-
-        /// Establishes an SVG coordinate system and viewport or embeds an SVG fragment.
-        ///
-        /// HTML tag: `<svg>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/svg)
-        pub struct svg;
-
-        /// Contains the top-level content of a MathML expression.
-        ///
-        /// HTML tag: `<math>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/math)
-        pub struct math;
-
-        /// Provides a drawable bitmap surface controlled through scripting APIs.
-        ///
-        /// HTML tag: `<canvas>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/canvas)
-        pub struct canvas;
-
-        /// Supplies fallback HTML when scripting is disabled or unsupported.
-        ///
-        /// HTML tag: `<noscript>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/noscript)
-        pub struct noscript;
-
-        /// Embeds executable code or data, or references it from an external resource.
-        ///
-        /// HTML tag: `<script>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/script)
-        pub struct script;
-
-        /// Marks a range of content removed from the document.
-        ///
-        /// HTML tag: `<del>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/del)
-        pub struct del;
-
-        /// Marks a range of content added to the document.
-        ///
-        /// HTML tag: `<ins>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/ins)
-        pub struct ins;
-
-        /// Provides the title or caption of a table.
-        ///
-        /// HTML tag: `<caption>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/caption)
-        pub struct caption;
-
-        /// Describes one or more columns within a table column group.
-        ///
-        /// HTML tag: `<col>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/col)
-        pub struct col;
-
-        /// Groups columns in a table for shared structure or styling.
-        ///
-        /// HTML tag: `<colgroup>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/colgroup)
-        pub struct colgroup;
-
-        /// Represents data arranged in rows and columns.
-        ///
-        /// HTML tag: `<table>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/table)
-        pub struct table;
-
-        /// Groups table rows that form the main body of table data.
-        ///
-        /// HTML tag: `<tbody>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/tbody)
-        pub struct tbody;
-
-        /// Defines a data cell within a table row.
-        ///
-        /// HTML tag: `<td>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/td)
-        pub struct td;
-
-        /// Groups table rows that summarize or conclude table columns.
-        ///
-        /// HTML tag: `<tfoot>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/tfoot)
-        pub struct tfoot;
-
-        /// Defines a header cell for a row, column, or group of table cells.
-        ///
-        /// HTML tag: `<th>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/th)
-        pub struct th;
-
-        /// Groups table rows that provide column-heading information.
-        ///
-        /// HTML tag: `<thead>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/thead)
-        pub struct thead;
-
-        /// Defines a row containing table data or header cells.
-        ///
-        /// HTML tag: `<tr>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/tr)
-        pub struct tr;
-
-        /// Creates an interactive control that performs an action when activated.
-        ///
-        /// HTML tag: `<button>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/button)
-        pub struct button;
-
-        /// Provides suggested or permitted options for another form control.
-        ///
-        /// HTML tag: `<datalist>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/datalist)
-        pub struct datalist;
-
-        /// Groups related form controls and their labels.
-        ///
-        /// HTML tag: `<fieldset>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/fieldset)
-        pub struct fieldset;
-
-        /// Contains interactive controls for collecting and submitting information.
-        ///
-        /// HTML tag: `<form>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/form)
-        pub struct form;
-
-        /// Creates a configurable form control for receiving user data.
-        ///
-        /// HTML tag: `<input>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/input)
-        pub struct input;
-
-        /// Provides a caption associated with a user-interface control.
-        ///
-        /// HTML tag: `<label>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/label)
-        pub struct label;
-
-        /// Captions the contents of a fieldset.
-        ///
-        /// HTML tag: `<legend>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/legend)
-        pub struct legend;
-
-        /// Displays a scalar or fractional value within a known range.
-        ///
-        /// HTML tag: `<meter>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/meter)
-        pub struct meter;
-
-        /// Groups related options inside a select control.
-        ///
-        /// HTML tag: `<optgroup>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/optgroup)
-        pub struct optgroup;
-
-        /// Defines a selectable item in a select, optgroup, or datalist.
-        ///
-        /// HTML tag: `<option>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/option)
-        pub struct option;
-
-        /// Holds the result of a calculation or user-triggered operation.
-        ///
-        /// HTML tag: `<output>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/output)
-        pub struct output;
-
-        /// Shows how much of a task has been completed.
-        ///
-        /// HTML tag: `<progress>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/progress)
-        pub struct progress;
-
-        /// Creates a control that lets the user choose from a menu of options.
-        ///
-        /// HTML tag: `<select>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/select)
-        pub struct select;
-
-        /// Displays the selected option’s content inside a closed customizable select.
-        ///
-        /// HTML tag: `<selectedcontent>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/selectedcontent)
-        pub struct selectedcontent;
-
-        /// Provides a multi-line plain-text editing control.
-        ///
-        /// HTML tag: `<textarea>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/textarea)
-        pub struct textarea;
-
-        /// Creates a disclosure widget whose additional information can be expanded or collapsed.
-        ///
-        /// HTML tag: `<details>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/details)
-        pub struct details;
-
-        /// Represents a dialog, alert, inspector, or other temporary interactive surface.
-        ///
-        /// HTML tag: `<dialog>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/dialog)
-        pub struct dialog;
-
-        /// Creates a control through which the user may share location data with the page.
-        ///
-        /// HTML tag: `<geolocation>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/geolocation)
-        pub struct geolocation;
-
-        /// Provides the visible label that toggles a parent details disclosure.
-        ///
-        /// HTML tag: `<summary>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/summary)
-        pub struct summary;
-
-        /// Defines a placeholder where a web component receives assigned markup.
-        ///
-        /// HTML tag: `<slot>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/slot)
-        pub struct slot;
-
-        /// Stores inert HTML that scripts may instantiate later.
-        ///
-        /// HTML tag: `<template>`
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/template)
-        pub struct template;
-    }
-
-    pub mod attrs {
-        /// Lists the media types or file types that a server or file input is prepared to receive.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Attributes/accept)
-        pub struct accept;
-
-        /// Provides one or more candidate keyboard shortcuts for focusing or activating the element.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes/accesskey)
-        pub struct accesskey;
-
-        /// Gives the URL that receives and processes a submitted form.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Attributes/action)
-        pub struct action;
-
-        /// Defines the permissions policy applied to an embedded iframe.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Attributes/allow)
-        pub struct allow;
-
-        /// Lets a color input expose an alpha-channel or opacity control.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Attributes/alpha)
-        pub struct alpha;
-
-        /// Supplies a textual replacement for an image or image-based control.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Attributes/alt)
-        pub struct alt;
-
-        /// Controls automatic capitalization behavior for user-entered text.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes/autocapitalize)
-        pub struct autocapitalize;
-
-        /// Tells the browser whether and how it may automatically complete form values.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Attributes/autocomplete)
-        pub struct autocomplete;
-
-        /// Controls automatic correction of spelling errors in editable text.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes/autocorrect)
-        pub struct autocorrect;
-
-        /// Requests focus when the page loads or when an enclosing dialog opens.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes/autofocus)
-        pub struct autofocus;
-
-        /// Requests that audio or video begin playback automatically when permitted.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Attributes/autoplay)
-        pub struct autoplay;
-
-        /// Hints that a newly captured camera or microphone file should be offered by a file input.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Attributes/capture)
-        pub struct capture;
-
-        /// Declares the document character encoding on a meta element.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Attributes/charset)
-        pub struct charset;
-
-        /// Sets the initial selected state of a checkbox or radio input.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Attributes/checked)
-        pub struct checked;
-
-        /// Links a quotation or document edit to a source URL.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Attributes/cite)
-        pub struct cite;
-
-        /// Assigns one or more class names used by CSS and scripts to select the element.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes/class)
-        pub struct class;
-
-        /// Selects the color space used by a color input.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Attributes/colorspace)
-        pub struct colorspace;
-
-        /// Sets the visible width of a textarea in average character columns.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Attributes/cols)
-        pub struct cols;
-
-        /// Specifies how many table columns a cell spans.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Attributes/colspan)
-        pub struct colspan;
-
-        /// Provides the metadata value represented by a meta element.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Attributes/content)
-        pub struct content;
-
-        /// Controls whether the user may edit the element's contents.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes/contenteditable)
-        pub struct contenteditable;
-
-        /// Requests built-in playback controls for audio or video.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Attributes/controls)
-        pub struct controls;
-
-        /// Defines the coordinates of a clickable region in an image map.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Attributes/coords)
-        pub struct coords;
-
-        /// Configures whether a resource fetch uses Cross-Origin Resource Sharing.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Attributes/crossorigin)
-        pub struct crossorigin;
-
-        /// Specifies a Content Security Policy that an embedded document must enforce.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Attributes/csp)
-        pub struct csp;
-
-        /// Provides the URL of the resource loaded by an object element.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Attributes/data)
-        pub struct data;
-
-        /// Associates a machine-readable date or time with an edit or time element.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Attributes/datetime)
-        pub struct datetime;
-
-        /// Hints whether image decoding should occur synchronously, asynchronously, or automatically.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Attributes/decoding)
-        pub struct decoding;
-
-        /// Marks a text track as the default track when user preferences do not select another.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Attributes/default)
-        pub struct default;
-
-        /// Delays execution of an external classic script until document parsing has completed.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Attributes/defer)
-        pub struct defer;
-
-        /// Sets or automatically determines the direction of the element's text.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes/dir)
-        pub struct dir;
-
-        /// Adds the directionality of a submitted text control to the form data.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Attributes/dirname)
-        pub struct dirname;
-
-        /// Makes a supported form control unavailable for interaction and form submission.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Attributes/disabled)
-        pub struct disabled;
-
-        /// Indicates that following a hyperlink should download its target, optionally with a suggested filename.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Attributes/download)
-        pub struct download;
-
-        /// Controls whether the element may be dragged with the HTML drag-and-drop mechanism.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes/draggable)
-        pub struct draggable;
-
-        /// Registers the element for Element Timing performance observations under a chosen identifier.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Attributes/elementtiming)
-        pub struct elementtiming;
-
-        /// Selects the encoding used for form data sent with a POST submission.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Attributes/enctype)
-        pub struct enctype;
-
-        /// Hints the action label or icon shown on a virtual keyboard's enter key.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes/enterkeyhint)
-        pub struct enterkeyhint;
-
-        /// Re-exports named shadow parts from a nested shadow tree to an outer tree.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes/exportparts)
-        pub struct exportparts;
-
-        /// Hints that a resource fetch should receive high, low, or automatic priority.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Attributes/fetchpriority)
-        pub struct fetchpriority;
-
-        /// Associates a form-related element with a form elsewhere in the same document.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Attributes/form)
-        pub struct form;
-
-        /// Overrides the owning form's submission URL for a submit button.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Attributes/formaction)
-        pub struct formaction;
-
-        /// Overrides the owning form's submission encoding for a submit button.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Attributes/formenctype)
-        pub struct formenctype;
-
-        /// Overrides the owning form's HTTP submission method for a submit button.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Attributes/formmethod)
-        pub struct formmethod;
-
-        /// Skips constraint validation when a particular submit button submits its form.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Attributes/formnovalidate)
-        pub struct formnovalidate;
-
-        /// Overrides the browsing context used to display a submit button's form response.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Attributes/formtarget)
-        pub struct formtarget;
-
-        /// Lists the table header cells that describe a data or header cell.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Attributes/headers)
-        pub struct headers;
-
-        /// Sets the intrinsic rendered height of supported replaced or embedded elements.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Attributes/height)
-        pub struct height;
-
-        /// Marks the element as not currently relevant so it is normally not rendered.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes/hidden)
-        pub struct hidden;
-
-        /// Sets the boundary above which a meter value is considered in the high range.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Attributes/high)
-        pub struct high;
-
-        /// Provides the URL of a linked resource or hyperlink destination.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Attributes/href)
-        pub struct href;
-
-        /// Hints the human language of a linked resource.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Attributes/hreflang)
-        pub struct hreflang;
-
-        /// Assigns a document-wide unique identifier to the element.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes/id)
-        pub struct id;
-
-        /// Makes an element and its descendants non-interactive and unavailable to focus or selection.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes/inert)
-        pub struct inert;
-
-        /// Hints the type of virtual keyboard most suitable for editing the element.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes/inputmode)
-        pub struct inputmode;
-
-        /// Provides cryptographic hashes used to verify that a fetched resource has not been altered.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Attributes/integrity)
-        pub struct integrity;
-
-        /// Requests that a standard element behave as a registered customized built-in element.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes/is)
-        pub struct is;
-
-        /// Marks an image as part of a server-side image map.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Attributes/ismap)
-        pub struct ismap;
-
-        /// Provides the globally unique identifier of a microdata item.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes/itemid)
-        pub struct itemid;
-
-        /// Adds one or more named properties to a microdata item.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes/itemprop)
-        pub struct itemprop;
-
-        /// Associates additional, non-descendant properties with a microdata item by element ID.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes/itemref)
-        pub struct itemref;
-
-        /// Creates a microdata item and establishes the scope of its properties.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes/itemscope)
-        pub struct itemscope;
-
-        /// Identifies the vocabulary URL that defines a microdata item's type and properties.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes/itemtype)
-        pub struct itemtype;
-
-        /// Identifies the purpose of a media text track, such as subtitles or captions.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Attributes/kind)
-        pub struct kind;
-
-        /// Provides a user-visible label for an option group, option, or text track.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Attributes/label)
-        pub struct label;
-
-        /// Declares the language of the element's content or expected user input.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes/lang)
-        pub struct lang;
-
-        /// Connects an input to a datalist that supplies suggested values.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Attributes/list)
-        pub struct list;
-
-        /// Hints whether an image or iframe should load immediately or lazily.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Attributes/loading)
-        pub struct loading;
-
-        /// Sets the boundary below which a meter value is considered in the low range.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Attributes/low)
-        pub struct low;
-
-        /// Sets the greatest permitted or represented numeric, date, time, or progress value.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Attributes/max)
-        pub struct max;
-
-        /// Limits the maximum number of characters accepted by a text control.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Attributes/maxlength)
-        pub struct maxlength;
-
-        /// Describes the media conditions for which a linked resource or source is intended.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Attributes/media)
-        pub struct media;
-
-        /// Selects the HTTP method used to submit a form.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Attributes/method)
-        pub struct method;
-
-        /// Sets the smallest permitted or represented numeric, date, or time value.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Attributes/min)
-        pub struct min;
-
-        /// Sets the minimum number of characters required by a text control.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Attributes/minlength)
-        pub struct minlength;
-
-        /// Allows a control to accept or select more than one value.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Attributes/multiple)
-        pub struct multiple;
-
-        /// Sets the initial muted state of audio or video.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Attributes/muted)
-        pub struct muted;
-
-        /// Assigns a name used for form submission, lookup, grouping, or element-specific identification.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Attributes/name)
-        pub struct name;
-
-        /// Supplies a one-time cryptographic token used by Content Security Policy.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes/nonce)
-        pub struct nonce;
-
-        /// Disables constraint validation when the form is submitted.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Attributes/novalidate)
-        pub struct novalidate;
-
-        /// Sets the initial open state of a details disclosure or dialog.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Attributes/open)
-        pub struct open;
-
-        /// Identifies the preferred value within a meter's range.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Attributes/optimum)
-        pub struct optimum;
-
-        /// Assigns one or more shadow-part names that may be styled through the part pseudo-element.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes/part)
-        pub struct part;
-
-        /// Provides a regular expression that a supported input value must match.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Attributes/pattern)
-        pub struct pattern;
-
-        /// Lists URLs that should receive hyperlink-following audit notifications.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Attributes/ping)
-        pub struct ping;
-
-        /// Displays a short hint inside an empty text-entry control.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Attributes/placeholder)
-        pub struct placeholder;
-
-        /// Requests inline video playback rather than forced full-screen playback.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Attributes/playsinline)
-        pub struct playsinline;
-
-        /// Turns the element into a popover that can be shown and hidden through invokers or script.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes/popover)
-        pub struct popover;
-
-        /// Provides an image displayed before video playback begins or a frame becomes available.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Attributes/poster)
-        pub struct poster;
-
-        /// Hints how much audio or video data the browser should fetch before playback.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Attributes/preload)
-        pub struct preload;
-
-        /// Prevents the user from editing a supported form control while retaining its submitted value.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Attributes/readonly)
-        pub struct readonly;
-
-        /// Selects the referrer information sent when fetching or navigating to a resource.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Attributes/referrerpolicy)
-        pub struct referrerpolicy;
-
-        /// Describes the relationship between the current document and a linked resource or destination.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Attributes/rel)
-        pub struct rel;
-
-        /// Requires the user to provide or select a value before form submission.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Attributes/required)
-        pub struct required;
-
-        /// Displays an ordered list with descending item numbers.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Attributes/reversed)
-        pub struct reversed;
-
-        /// Assigns an explicit ARIA role that defines the element's accessibility semantics.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Reference/Roles)
-        pub struct role;
-
-        /// Sets the visible height of a textarea in text rows.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Attributes/rows)
-        pub struct rows;
-
-        /// Specifies how many table rows a cell spans.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Attributes/rowspan)
-        pub struct rowspan;
-
-        /// Applies a configurable set of restrictions to an embedded iframe document.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Attributes/sandbox)
-        pub struct sandbox;
-
-        /// Identifies the table cells for which a header cell provides heading information.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Attributes/scope)
-        pub struct scope;
-
-        /// Sets the initial selected state of an option.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Attributes/selected)
-        pub struct selected;
-
-        /// Selects the geometric shape used for an image-map area.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Attributes/shape)
-        pub struct shape;
-
-        /// Sets the visible character width of an input or the visible option count of a select.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Attributes/size)
-        pub struct size;
-
-        /// Describes responsive image slot sizes or icon dimensions for linked resources.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Attributes/sizes)
-        pub struct sizes;
-
-        /// Assigns an element to a named slot in a shadow tree.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes/slot)
-        pub struct slot;
-
-        /// Sets how many table columns are covered by a col or colgroup.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Attributes/span)
-        pub struct span;
-
-        /// Controls whether spelling and grammar checking may be offered for editable text.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes/spellcheck)
-        pub struct spellcheck;
-
-        /// Provides the URL of embedded media, script, image, frame, or other external content.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Attributes/src)
-        pub struct src;
-
-        /// Provides inline HTML content for an iframe instead of loading a separate URL.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Attributes/srcdoc)
-        pub struct srcdoc;
-
-        /// Declares the language of a media text track.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Attributes/srclang)
-        pub struct srclang;
-
-        /// Lists responsive image candidates and their width or pixel-density descriptors.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Attributes/srcset)
-        pub struct srcset;
-
-        /// Sets the first ordinal value used by an ordered list.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Attributes/start)
-        pub struct start;
-
-        /// Sets the permitted value interval or granularity for a supported input.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Attributes/step)
-        pub struct step;
-
-        /// Contains inline CSS declarations applied directly to the element.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes/style)
-        pub struct style;
-
-        /// Controls keyboard focusability and the element's position in sequential focus navigation.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes/tabindex)
-        pub struct tabindex;
-
-        /// Selects the browsing context used for a link destination or form response.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Attributes/target)
-        pub struct target;
-
-        /// Provides advisory information that user agents may present, often as a tooltip.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes/title)
-        pub struct title;
-
-        /// Controls whether the element's translatable content should be localized.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes/translate)
-        pub struct translate;
-
-        /// Associates an image or object with a client-side image map.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Attributes/usemap)
-        pub struct usemap;
-
-        /// Provides an element-specific value, initial value, or submitted value.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Attributes/value)
-        pub struct value;
-
-        /// Controls whether focusing editable content automatically shows the virtual keyboard.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes/virtualkeyboardpolicy)
-        pub struct virtualkeyboardpolicy;
-
-        /// Sets the intrinsic rendered width of supported replaced or embedded elements.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Attributes/width)
-        pub struct width;
-
-        /// Controls how textarea text is wrapped and represented during form submission.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Attributes/wrap)
-        pub struct wrap;
-
-        /// Controls whether browser-provided writing suggestions are enabled for the element.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes/writingsuggestions)
-        pub struct writingsuggestions;
-
-        /// Runs inline handler code when the `abort` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct onabort;
-
-        /// Runs inline handler code when the `animationcancel` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct onanimationcancel;
-
-        /// Runs inline handler code when the `animationend` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct onanimationend;
-
-        /// Runs inline handler code when the `animationiteration` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct onanimationiteration;
-
-        /// Runs inline handler code when the `animationstart` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct onanimationstart;
-
-        /// Runs inline handler code when the `auxclick` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct onauxclick;
-
-        /// Runs inline handler code when the `beforeinput` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct onbeforeinput;
-
-        /// Runs inline handler code when the `beforematch` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct onbeforematch;
-
-        /// Runs inline handler code when the `beforetoggle` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct onbeforetoggle;
-
-        /// Runs inline handler code when the `blur` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct onblur;
-
-        /// Runs inline handler code when the `cancel` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct oncancel;
-
-        /// Runs inline handler code when the `canplay` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct oncanplay;
-
-        /// Runs inline handler code when the `canplaythrough` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct oncanplaythrough;
-
-        /// Runs inline handler code when the `change` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct onchange;
-
-        /// Runs inline handler code when the `click` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct onclick;
-
-        /// Runs inline handler code when the `close` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct onclose;
-
-        /// Runs inline handler code when the `command` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct oncommand;
-
-        /// Runs inline handler code when the `contentvisibilityautostatechange` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct oncontentvisibilityautostatechange;
-
-        /// Runs inline handler code when the `contextlost` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct oncontextlost;
-
-        /// Runs inline handler code when the `contextmenu` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct oncontextmenu;
-
-        /// Runs inline handler code when the `contextrestored` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct oncontextrestored;
-
-        /// Runs inline handler code when the `copy` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct oncopy;
-
-        /// Runs inline handler code when the `cuechange` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct oncuechange;
-
-        /// Runs inline handler code when the `cut` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct oncut;
-
-        /// Runs inline handler code when the `dblclick` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct ondblclick;
-
-        /// Runs inline handler code when the `drag` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct ondrag;
-
-        /// Runs inline handler code when the `dragend` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct ondragend;
-
-        /// Runs inline handler code when the `dragenter` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct ondragenter;
-
-        /// Runs inline handler code when the `dragleave` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct ondragleave;
-
-        /// Runs inline handler code when the `dragover` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct ondragover;
-
-        /// Runs inline handler code when the `dragstart` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct ondragstart;
-
-        /// Runs inline handler code when the `drop` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct ondrop;
-
-        /// Runs inline handler code when the `durationchange` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct ondurationchange;
-
-        /// Runs inline handler code when the `emptied` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct onemptied;
-
-        /// Runs inline handler code when the `ended` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct onended;
-
-        /// Runs inline handler code when the `error` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct onerror;
-
-        /// Runs inline handler code when the `focus` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct onfocus;
-
-        /// Runs inline handler code when the `focusin` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct onfocusin;
-
-        /// Runs inline handler code when the `focusout` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct onfocusout;
-
-        /// Runs inline handler code when the `formdata` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct onformdata;
-
-        /// Runs inline handler code when the `fullscreenchange` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct onfullscreenchange;
-
-        /// Runs inline handler code when the `fullscreenerror` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct onfullscreenerror;
-
-        /// Runs inline handler code when the `gotpointercapture` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct ongotpointercapture;
-
-        /// Runs inline handler code when the `input` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct oninput;
-
-        /// Runs inline handler code when the `invalid` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct oninvalid;
-
-        /// Runs inline handler code when the `keydown` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct onkeydown;
-
-        /// Runs inline handler code when the `keyup` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct onkeyup;
-
-        /// Runs inline handler code when the `load` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct onload;
-
-        /// Runs inline handler code when the `loadeddata` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct onloadeddata;
-
-        /// Runs inline handler code when the `loadedmetadata` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct onloadedmetadata;
-
-        /// Runs inline handler code when the `loadstart` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct onloadstart;
-
-        /// Runs inline handler code when the `lostpointercapture` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct onlostpointercapture;
-
-        /// Runs inline handler code when the `mousedown` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct onmousedown;
-
-        /// Runs inline handler code when the `mouseenter` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct onmouseenter;
-
-        /// Runs inline handler code when the `mouseleave` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct onmouseleave;
-
-        /// Runs inline handler code when the `mousemove` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct onmousemove;
-
-        /// Runs inline handler code when the `mouseout` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct onmouseout;
-
-        /// Runs inline handler code when the `mouseover` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct onmouseover;
-
-        /// Runs inline handler code when the `mouseup` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct onmouseup;
-
-        /// Runs inline handler code when the `paste` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct onpaste;
-
-        /// Runs inline handler code when the `pause` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct onpause;
-
-        /// Runs inline handler code when the `play` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct onplay;
-
-        /// Runs inline handler code when the `playing` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct onplaying;
-
-        /// Runs inline handler code when the `pointercancel` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct onpointercancel;
-
-        /// Runs inline handler code when the `pointerdown` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct onpointerdown;
-
-        /// Runs inline handler code when the `pointerenter` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct onpointerenter;
-
-        /// Runs inline handler code when the `pointerleave` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct onpointerleave;
-
-        /// Runs inline handler code when the `pointermove` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct onpointermove;
-
-        /// Runs inline handler code when the `pointerout` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct onpointerout;
-
-        /// Runs inline handler code when the `pointerover` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct onpointerover;
-
-        /// Runs inline handler code when the `pointerrawupdate` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct onpointerrawupdate;
-
-        /// Runs inline handler code when the `pointerup` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct onpointerup;
-
-        /// Runs inline handler code when the `progress` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct onprogress;
-
-        /// Runs inline handler code when the `ratechange` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct onratechange;
-
-        /// Runs inline handler code when the `reset` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct onreset;
-
-        /// Runs inline handler code when the `resize` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct onresize;
-
-        /// Runs inline handler code when the `scroll` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct onscroll;
-
-        /// Runs inline handler code when the `scrollend` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct onscrollend;
-
-        /// Runs inline handler code when the `scrollsnapchange` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct onscrollsnapchange;
-
-        /// Runs inline handler code when the `scrollsnapchanging` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct onscrollsnapchanging;
-
-        /// Runs inline handler code when the `securitypolicyviolation` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct onsecuritypolicyviolation;
-
-        /// Runs inline handler code when the `seeked` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct onseeked;
-
-        /// Runs inline handler code when the `seeking` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct onseeking;
-
-        /// Runs inline handler code when the `select` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct onselect;
-
-        /// Runs inline handler code when the `selectionchange` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct onselectionchange;
-
-        /// Runs inline handler code when the `selectstart` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct onselectstart;
-
-        /// Runs inline handler code when the `slotchange` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct onslotchange;
-
-        /// Runs inline handler code when the `stalled` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct onstalled;
-
-        /// Runs inline handler code when the `submit` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct onsubmit;
-
-        /// Runs inline handler code when the `suspend` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct onsuspend;
-
-        /// Runs inline handler code when the `timeupdate` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct ontimeupdate;
-
-        /// Runs inline handler code when the `toggle` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct ontoggle;
-
-        /// Runs inline handler code when the `touchcancel` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct ontouchcancel;
-
-        /// Runs inline handler code when the `touchend` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct ontouchend;
-
-        /// Runs inline handler code when the `touchmove` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct ontouchmove;
-
-        /// Runs inline handler code when the `touchstart` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct ontouchstart;
-
-        /// Runs inline handler code when the `transitioncancel` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct ontransitioncancel;
-
-        /// Runs inline handler code when the `transitionend` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct ontransitionend;
-
-        /// Runs inline handler code when the `transitionrun` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct ontransitionrun;
-
-        /// Runs inline handler code when the `transitionstart` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct ontransitionstart;
-
-        /// Runs inline handler code when the `volumechange` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct onvolumechange;
-
-        /// Runs inline handler code when the `waiting` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct onwaiting;
-
-        /// Runs inline handler code when the `wheel` event is dispatched.
-        ///
-        /// [MDN reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes#list_of_global_event_handler_attributes)
-        pub struct onwheel;
-    }
+    pub use avosetta_names::{attrs, elements};
 }
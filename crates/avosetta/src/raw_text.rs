@@ -0,0 +1,204 @@
+use crate::{Buffer, Html, HtmlRef};
+
+/// Renders a value as the raw text content of a `<script>` or `<style>`
+/// element.
+///
+/// The body of a raw text element is not parsed for character references, so
+/// entity escaping would corrupt it: `a < b` must be written as-is, not as
+/// `a &lt; b`. Instead, text is written unescaped, except that any `</` or
+/// `<!` sequence is written as `<\/` or `<\!`. This stops untrusted content
+/// from closing the element early with `</script>`, or from opening a comment
+/// that changes where the element ends, while keeping JavaScript string
+/// literals and CSS values equivalent.
+///
+/// [`asx!`](crate::asx) applies this automatically to values interpolated in
+/// the body of a `<script>` or `<style>` element. Values written through
+/// [`Raw`](crate::Raw) are trusted and written unchanged.
+///
+/// # Example
+///
+/// ```rust
+/// use avosetta::{Html, RawText};
+///
+/// let mut output = String::new();
+/// RawText("if (a < b && c) { x = '</script>'; }").write(&mut output);
+///
+/// assert_eq!(output, r"if (a < b && c) { x = '<\/script>'; }");
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RawText<T>(pub T);
+
+impl<T, B> Html<B> for RawText<T>
+where
+    T: for<'a> Html<RawTextBuffer<'a, B>>,
+    B: Buffer + ?Sized,
+{
+    #[inline]
+    fn write(self, s: &mut B) {
+        self.0.write(&mut RawTextBuffer::new(s));
+    }
+}
+
+impl<T, B> HtmlRef<B> for RawText<T>
+where
+    T: for<'a> HtmlRef<RawTextBuffer<'a, B>>,
+    B: Buffer + ?Sized,
+{
+    #[inline]
+    fn write_ref(&self, s: &mut B) {
+        self.0.write_ref(&mut RawTextBuffer::new(s));
+    }
+}
+
+/// A [`Buffer`] adapter that escapes text for the body of a `<script>` or
+/// `<style>` element.
+///
+/// Markup written with [`Buffer::push_str`] passes through unchanged, while
+/// text written with [`Buffer::push_text`] is escaped as described for
+/// [`RawText`]. The adapter remembers whether the last byte written was a `<`,
+/// so that a `</` or `<!` split across writes is escaped too, unless both of
+/// its bytes were written as markup.
+#[derive(Debug)]
+pub struct RawTextBuffer<'a, B>
+where
+    B: Buffer + ?Sized,
+{
+    inner: &'a mut B,
+    pending_lt: Option<Source>,
+}
+
+/// How the last byte written to a [`RawTextBuffer`] was written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Source {
+    Markup,
+    Text,
+}
+
+impl<'a, B> RawTextBuffer<'a, B>
+where
+    B: Buffer + ?Sized,
+{
+    /// Creates an adapter that writes to `inner`.
+    #[inline]
+    pub fn new(inner: &'a mut B) -> Self {
+        Self {
+            inner,
+            pending_lt: None,
+        }
+    }
+}
+
+impl<B> Buffer for RawTextBuffer<'_, B>
+where
+    B: Buffer + ?Sized,
+{
+    #[inline]
+    fn push_str(&mut self, s: &str) {
+        if s.is_empty() {
+            return;
+        }
+
+        if self.pending_lt == Some(Source::Text) && s.starts_with(['/', '!']) {
+            self.inner.push_str("\\");
+        }
+
+        self.pending_lt = s.ends_with('<').then_some(Source::Markup);
+        self.inner.push_str(s);
+    }
+
+    #[inline]
+    fn reserve(&mut self, additional: usize) {
+        self.inner.reserve(additional);
+    }
+
+    fn push_text(&mut self, s: &str) {
+        let mut start = 0;
+
+        for (i, byte) in s.bytes().enumerate() {
+            if self.pending_lt.is_some() && matches!(byte, b'/' | b'!') {
+                self.inner.push_str(&s[start..i]);
+                self.inner.push_str("\\");
+                start = i;
+            }
+
+            self.pending_lt = (byte == b'<').then_some(Source::Text);
+        }
+
+        self.inner.push_str(&s[start..]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn render(f: impl FnOnce(&mut RawTextBuffer<String>)) -> String {
+        let mut s = String::new();
+        f(&mut RawTextBuffer::new(&mut s));
+        s
+    }
+
+    #[test]
+    fn escapes_closing_tags_in_text() {
+        assert_eq!(render(|s| s.push_text("</script>")), r"<\/script>");
+        assert_eq!(render(|s| s.push_text("<!--")), r"<\!--");
+        assert_eq!(render(|s| s.push_text("a < b")), "a < b");
+    }
+
+    #[test]
+    fn escapes_text_after_markup_lt() {
+        let html = render(|s| {
+            s.push_str("var a = 1 <");
+            s.push_text("/script><script>alert(1)");
+        });
+
+        assert_eq!(html, r"var a = 1 <\/script><script>alert(1)");
+    }
+
+    #[test]
+    fn escapes_markup_after_text_lt() {
+        let html = render(|s| {
+            s.push_text("<");
+            s.push_str("/script>");
+        });
+
+        assert_eq!(html, r"<\/script>");
+    }
+
+    #[test]
+    fn escapes_across_text_writes() {
+        let html = render(|s| {
+            s.push_text("<");
+            s.push_text("");
+            s.push_text("!--");
+        });
+
+        assert_eq!(html, r"<\!--");
+    }
+
+    #[test]
+    #[cfg(feature = "macros")]
+    fn escapes_interpolations_after_literals() {
+        let x = "/script><script>alert(1)";
+        let y = "<";
+
+        let mut html = String::new();
+        crate::asx! { script { "var a = 1 <" @x } }.write(&mut html);
+        crate::asx! { style { @y "/style>" } }.write(&mut html);
+
+        assert_eq!(
+            html,
+            r"<script>var a = 1 <\/script><script>alert(1)</script><style><\/style></style>",
+        );
+    }
+
+    #[test]
+    fn keeps_markup_unchanged() {
+        let html = render(|s| {
+            s.push_str("<");
+            s.push_str("/script>");
+        });
+
+        assert_eq!(html, "</script>");
+    }
+}
@@ -0,0 +1,733 @@
+use avosetta_names::{VOID_ELEMENTS, attrs, elements};
+
+use crate::{Buffer, EscapeContext, Html, HtmlRef, Url};
+
+/// Renders untrusted HTML, keeping only the elements and attributes allowed by
+/// a [`SanitizePolicy`].
+///
+/// The input is parsed leniently, the way browsers parse markup. Allowed
+/// elements are written back out with their allowed attributes, and every other
+/// tag is removed while its text is kept, except that the contents of elements
+/// such as `<script>` and `<style>` are removed as well. Comments and other
+/// markup declarations are removed, text is escaped, and elements left open at
+/// the end of the input are closed, so the output cannot affect the markup
+/// around it. URL attributes such as `href` are kept only if their scheme is
+/// allowed by the policy; see [`Url`].
+///
+/// `Sanitize` is a safe alternative to [`Raw`](crate::Raw) for user-authored
+/// rich text.
+///
+/// # Example
+///
+/// ```rust
+/// use avosetta::{asx, Html, Sanitize, SanitizePolicy};
+///
+/// let comment = r#"<p onclick="steal()">Hi <b>there</b><script>steal()</script>
+/// <a href="javascript:steal()">click</a> <a href="https://example.com">me</a>"#;
+///
+/// let page = asx! {
+///     article { @Sanitize::new(comment, &SanitizePolicy::LINKS) }
+/// };
+///
+/// let mut output = String::new();
+/// page.write(&mut output);
+///
+/// assert_eq!(
+///     output,
+///     "<article><p>Hi <b>there</b>\n<a>click</a> <a href=\"https://example.com\">me</a></p></article>",
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Sanitize<'a, T> {
+    value: T,
+    policy: &'a SanitizePolicy,
+}
+
+impl<'a, T> Sanitize<'a, T>
+where
+    T: AsRef<str>,
+{
+    /// Creates a value that renders `value` sanitized with `policy`.
+    #[inline]
+    pub const fn new(value: T, policy: &'a SanitizePolicy) -> Self {
+        Self { value, policy }
+    }
+
+    /// Consumes the `Sanitize`, returning the wrapped value.
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T, B> Html<B> for Sanitize<'_, T>
+where
+    T: AsRef<str>,
+    B: Buffer + ?Sized,
+{
+    #[inline]
+    fn write(self, s: &mut B) {
+        sanitize(self.value.as_ref(), self.policy, s);
+    }
+}
+
+impl<T, B> HtmlRef<B> for Sanitize<'_, T>
+where
+    T: AsRef<str>,
+    B: Buffer + ?Sized,
+{
+    #[inline]
+    fn write_ref(&self, s: &mut B) {
+        sanitize(self.value.as_ref(), self.policy, s);
+    }
+}
+
+/// The elements and attributes kept by [`Sanitize`].
+///
+/// A policy is an allow-list: elements that are not listed are removed, and so
+/// are attributes that are not listed for their element. Policies are checked
+/// when they are created, so they are best declared as constants, where a
+/// mistake is a compile error.
+///
+/// # Example
+///
+/// ```rust
+/// use avosetta::{Html, Sanitize, SanitizePolicy};
+///
+/// const QUOTES: SanitizePolicy = SanitizePolicy::new(
+///     &["blockquote", "p", "em"],
+///     &[("blockquote", "cite"), ("*", "lang")],
+/// );
+///
+/// let mut output = String::new();
+/// Sanitize::new(r#"<blockquote lang="en" class="x"><p>Hi</blockquote>"#, &QUOTES)
+///     .write(&mut output);
+///
+/// assert_eq!(output, r#"<blockquote lang="en"><p>Hi</p></blockquote>"#);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SanitizePolicy {
+    elements: &'static [&'static str],
+    attrs: &'static [(&'static str, &'static str)],
+    url_schemes: &'static [&'static str],
+}
+
+macro_rules! formatting_elements {
+    ($($extra:literal),*) => {
+        &[
+            "abbr", "b", "blockquote", "br", "cite", "code", "dd", "del", "dl", "dt", "em", "h1",
+            "h2", "h3", "h4", "h5", "h6", "hr", "i", "ins", "kbd", "li", "mark", "ol", "p", "pre",
+            "q", "s", "small", "span", "strong", "sub", "sup", "u", "ul", $($extra),*
+        ]
+    };
+}
+
+macro_rules! formatting_attrs {
+    ($($extra:expr),*) => {
+        &[
+            ("abbr", "title"),
+            ("blockquote", "cite"),
+            ("del", "cite"),
+            ("ins", "cite"),
+            ("ol", "reversed"),
+            ("ol", "start"),
+            ("q", "cite"),
+            $($extra),*
+        ]
+    };
+}
+
+impl SanitizePolicy {
+    /// Allows common text formatting: paragraphs, headings, lists, quotes,
+    /// code, and inline elements such as `<b>` and `<em>`.
+    pub const FORMATTING: Self = Self::new(formatting_elements!(), formatting_attrs!());
+
+    /// Allows everything in [`SanitizePolicy::FORMATTING`], as well as links
+    /// with an `href` and a `title`.
+    pub const LINKS: Self = Self::new(
+        formatting_elements!("a"),
+        formatting_attrs!(("a", "href"), ("a", "title")),
+    );
+
+    /// Creates a policy allowing the lowercase element names in `elements`, and
+    /// the attributes in `attrs` as `(element, attribute)` pairs. An element of
+    /// `"*"` allows the attribute on every allowed element.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a name is not a known HTML element or attribute, if an
+    /// attribute is listed for an element that is not allowed, or if a name is
+    /// one that a sanitizer must never allow, such as `script` or `onclick`.
+    /// Attribute names starting with `data-` or `aria-` are always accepted.
+    pub const fn new(
+        elements: &'static [&'static str],
+        attrs: &'static [(&'static str, &'static str)],
+    ) -> Self {
+        let mut i = 0;
+
+        while i < elements.len() {
+            assert!(
+                contains(elements::NAMES, elements[i]),
+                "unknown element in sanitizer policy"
+            );
+
+            assert!(
+                !contains(UNSAFE_ELEMENTS, elements[i]),
+                "element cannot be allowed by a sanitizer policy"
+            );
+
+            i += 1;
+        }
+
+        let mut i = 0;
+
+        while i < attrs.len() {
+            let (element, attr) = attrs[i];
+
+            assert!(
+                eq(element, "*") || contains(elements, element),
+                "sanitizer policy allows an attribute on an element it does not allow"
+            );
+
+            assert!(
+                contains(attrs::NAMES, attr)
+                    || starts_with(attr, "data-")
+                    || starts_with(attr, "aria-"),
+                "unknown attribute in sanitizer policy"
+            );
+
+            assert!(
+                !starts_with(attr, "on") && !contains(UNSAFE_ATTRS, attr),
+                "attribute cannot be allowed by a sanitizer policy"
+            );
+
+            i += 1;
+        }
+
+        Self {
+            elements,
+            attrs,
+            url_schemes: Url::DEFAULT_SCHEMES,
+        }
+    }
+
+    /// Returns the policy with URL attributes allowed only if they are
+    /// relative or use one of the lowercase schemes in `schemes`.
+    ///
+    /// Policies allow [`Url::DEFAULT_SCHEMES`] unless this is called.
+    #[inline]
+    pub const fn with_url_schemes(mut self, schemes: &'static [&'static str]) -> Self {
+        self.url_schemes = schemes;
+        self
+    }
+
+    fn element(&self, name: &str) -> Option<&'static str> {
+        self.elements
+            .iter()
+            .copied()
+            .find(|x| x.eq_ignore_ascii_case(name))
+    }
+
+    fn attr(&self, element: &str, name: &str) -> Option<&'static str> {
+        self.attrs
+            .iter()
+            .find(|(x, y)| (*x == "*" || *x == element) && y.eq_ignore_ascii_case(name))
+            .map(|(_, y)| *y)
+    }
+}
+
+/// Elements that run code, load other documents, or change how the rest of the
+/// page is parsed.
+const UNSAFE_ELEMENTS: &[&str] = &[
+    "base",
+    "body",
+    "embed",
+    "fencedframe",
+    "head",
+    "html",
+    "iframe",
+    "link",
+    "math",
+    "meta",
+    "noscript",
+    "object",
+    "script",
+    "style",
+    "svg",
+    "template",
+    "textarea",
+    "title",
+];
+
+const UNSAFE_ATTRS: &[&str] = &["srcdoc", "style"];
+
+/// Elements whose contents are removed along with them.
+const DROPPED_ELEMENTS: &[&str] = &[
+    "iframe",
+    "math",
+    "noembed",
+    "noframes",
+    "noscript",
+    "object",
+    "plaintext",
+    "script",
+    "style",
+    "svg",
+    "template",
+    "textarea",
+    "title",
+    "xmp",
+];
+
+const fn eq(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+
+    if a.len() != b.len() {
+        return false;
+    }
+
+    let mut i = 0;
+
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+
+        i += 1;
+    }
+
+    true
+}
+
+const fn starts_with(s: &str, prefix: &str) -> bool {
+    s.len() >= prefix.len() && eq(s.split_at(prefix.len()).0, prefix)
+}
+
+const fn contains(list: &[&str], name: &str) -> bool {
+    let mut i = 0;
+
+    while i < list.len() {
+        if eq(list[i], name) {
+            return true;
+        }
+
+        i += 1;
+    }
+
+    false
+}
+
+#[inline]
+fn contains_ignore_case(list: &[&str], name: &str) -> bool {
+    list.iter().any(|x| x.eq_ignore_ascii_case(name))
+}
+
+struct Tag<'a> {
+    name: &'a str,
+    attrs: Vec<(&'a str, Option<&'a str>)>,
+}
+
+/// The markup at the start of the input, and the input that follows it.
+enum Markup<'a> {
+    Start(Tag<'a>),
+    End(&'a str),
+    Ignored,
+    Text,
+}
+
+fn sanitize<B>(input: &str, policy: &SanitizePolicy, s: &mut B)
+where
+    B: Buffer + ?Sized,
+{
+    let mut open = Vec::<&'static str>::new();
+    let mut rest = input;
+
+    s.reserve(input.len());
+
+    while let Some(i) = rest.find('<') {
+        write_text(&rest[..i], s);
+
+        let Some((markup, next)) = parse_markup(&rest[i..]) else {
+            // An unfinished tag at the end of the input is removed.
+            rest = "";
+            break;
+        };
+
+        match markup {
+            Markup::Start(tag) => match policy.element(tag.name) {
+                Some(name) => {
+                    write_start(name, &tag, policy, s);
+
+                    if !contains(VOID_ELEMENTS, name) {
+                        open.push(name);
+                    }
+                }
+
+                None if contains_ignore_case(DROPPED_ELEMENTS, tag.name) => {
+                    rest = skip_contents(next, tag.name);
+                    continue;
+                }
+
+                None => {}
+            },
+
+            Markup::End(name) => {
+                if let Some(i) = open.iter().rposition(|x| x.eq_ignore_ascii_case(name)) {
+                    for name in open.drain(i..).rev() {
+                        write_end(name, s);
+                    }
+                }
+            }
+
+            Markup::Ignored => {}
+            Markup::Text => s.push_str("&lt;"),
+        }
+
+        rest = next;
+    }
+
+    write_text(rest, s);
+
+    for name in open.into_iter().rev() {
+        write_end(name, s);
+    }
+}
+
+/// Parses the markup starting with the `<` at the start of `input`, returning
+/// `None` if the input ends before the markup does.
+fn parse_markup(input: &str) -> Option<(Markup<'_>, &str)> {
+    let after = &input[1..];
+
+    if let Some(comment) = after.strip_prefix("!--") {
+        let end = comment.find("-->").map_or(comment.len(), |x| x + 3);
+        return Some((Markup::Ignored, &comment[end..]));
+    }
+
+    if after.starts_with(['!', '?']) {
+        let end = after.find('>')?;
+        return Some((Markup::Ignored, &after[end + 1..]));
+    }
+
+    if let Some(end) = after.strip_prefix('/') {
+        if !end.starts_with(|x: char| x.is_ascii_alphabetic()) {
+            let end_of_tag = end.find('>')?;
+            return Some((Markup::Ignored, &end[end_of_tag + 1..]));
+        }
+
+        let (name, rest) = split_name(end);
+        let end_of_tag = rest.find('>')?;
+        return Some((Markup::End(name), &rest[end_of_tag + 1..]));
+    }
+
+    if !after.starts_with(|x: char| x.is_ascii_alphabetic()) {
+        return Some((Markup::Text, after));
+    }
+
+    let (name, mut rest) = split_name(after);
+    let mut attrs = Vec::new();
+
+    loop {
+        rest = rest.trim_start_matches(|x: char| x.is_ascii_whitespace() || x == '/');
+
+        if let Some(rest) = rest.strip_prefix('>') {
+            return Some((Markup::Start(Tag { name, attrs }), rest));
+        }
+
+        if rest.is_empty() {
+            return None;
+        }
+
+        // An attribute name may start with `=`, but cannot contain one.
+        let first = rest.chars().next().map_or(0, char::len_utf8);
+        let len = rest[first..]
+            .find(|x: char| x.is_ascii_whitespace() || matches!(x, '/' | '>' | '='))
+            .map_or(rest.len(), |x| x + first);
+
+        let attr = &rest[..len];
+        rest = rest[len..].trim_start_matches(|x: char| x.is_ascii_whitespace());
+
+        let Some(value) = rest.strip_prefix('=') else {
+            attrs.push((attr, None));
+            continue;
+        };
+
+        let value = value.trim_start_matches(|x: char| x.is_ascii_whitespace());
+
+        let (value, next) = match value.chars().next() {
+            Some(quote @ ('"' | '\'')) => {
+                let end = value[1..].find(quote)?;
+                (&value[1..end + 1], &value[end + 2..])
+            }
+
+            _ => {
+                let end = value
+                    .find(|x: char| x.is_ascii_whitespace() || x == '>')
+                    .unwrap_or(value.len());
+
+                (&value[..end], &value[end..])
+            }
+        };
+
+        attrs.push((attr, Some(value)));
+        rest = next;
+    }
+}
+
+/// Splits a tag name from the start of `input`.
+fn split_name(input: &str) -> (&str, &str) {
+    let len = input
+        .find(|x: char| x.is_ascii_whitespace() || matches!(x, '/' | '>'))
+        .unwrap_or(input.len());
+
+    input.split_at(len)
+}
+
+/// Skips the input up to and including the end tag of the element `name`.
+fn skip_contents<'a>(input: &'a str, name: &str) -> &'a str {
+    let mut rest = input;
+
+    while let Some(i) = rest.find("</") {
+        rest = &rest[i + 2..];
+
+        let (end, after) = split_name(rest);
+
+        if end.eq_ignore_ascii_case(name) {
+            return after.find('>').map_or("", |x| &after[x + 1..]);
+        }
+    }
+
+    ""
+}
+
+fn write_start<B>(name: &str, tag: &Tag<'_>, policy: &SanitizePolicy, s: &mut B)
+where
+    B: Buffer + ?Sized,
+{
+    let mut written = Vec::<&str>::new();
+
+    s.push('<');
+    s.push_str(name);
+
+    for (attr, value) in &tag.attrs {
+        let Some(attr) = policy.attr(name, attr) else {
+            continue;
+        };
+
+        // Browsers keep the first of several attributes with the same name.
+        if written.contains(&attr) {
+            continue;
+        }
+
+        written.push(attr);
+
+        let value = value.unwrap_or(attr);
+
+        if EscapeContext::for_attribute(attr) == EscapeContext::Url
+            && !decode(value).is_some_and(|x| Url::with_schemes(x, policy.url_schemes).is_allowed())
+        {
+            continue;
+        }
+
+        s.push(' ');
+        s.push_str(attr);
+        s.push_str("=\"");
+        write_text(value, s);
+        s.push('"');
+    }
+
+    s.push('>');
+}
+
+#[inline]
+fn write_end<B>(name: &str, s: &mut B)
+where
+    B: Buffer + ?Sized,
+{
+    s.push_str("</");
+    s.push_str(name);
+    s.push('>');
+}
+
+/// Writes text from the input, escaping it but keeping character references.
+fn write_text<B>(text: &str, s: &mut B)
+where
+    B: Buffer + ?Sized,
+{
+    let mut start = 0;
+
+    for (i, byte) in text.bytes().enumerate() {
+        let entity = match byte {
+            b'&' if is_reference(&text[i + 1..]) => continue,
+            b'&' => "&amp;",
+            b'<' => "&lt;",
+            b'>' => "&gt;",
+            b'"' => "&quot;",
+            b'\'' => "&#39;",
+
+            _ => continue,
+        };
+
+        s.push_str(&text[start..i]);
+        s.push_str(entity);
+        start = i + 1;
+    }
+
+    s.push_str(&text[start..]);
+}
+
+/// Returns `true` if `s` starts with the rest of a character reference ending
+/// in `;`, such as `amp;` or `#x27;`.
+fn is_reference(s: &str) -> bool {
+    let (digits, rest) = match s.strip_prefix('#') {
+        Some(x) => match x.strip_prefix(['x', 'X']) {
+            Some(x) => (x.bytes().take_while(u8::is_ascii_hexdigit).count(), x),
+            None => (x.bytes().take_while(u8::is_ascii_digit).count(), x),
+        },
+
+        None => (s.bytes().take_while(u8::is_ascii_alphanumeric).count(), s),
+    };
+
+    digits > 0 && rest[digits..].starts_with(';')
+}
+
+/// Decodes the character references in an attribute value, so that its URL
+/// scheme can be checked.
+///
+/// An `&` that does not start a reference is kept as it is, the way browsers
+/// read it, so query strings such as `?a=1&b=2` are left alone. Returns `None`
+/// if the value holds a named reference that is not understood here, since it
+/// could hide part of the scheme.
+fn decode(value: &str) -> Option<String> {
+    let mut out = String::with_capacity(value.len());
+    let mut rest = value;
+
+    while let Some(i) = rest.find('&') {
+        out.push_str(&rest[..i]);
+        rest = &rest[i + 1..];
+
+        if let Some(x) = rest.strip_prefix('#') {
+            let (x, radix) = match x.strip_prefix(['x', 'X']) {
+                Some(x) => (x, 16),
+                None => (x, 10),
+            };
+
+            let len = match radix {
+                16 => x.bytes().take_while(u8::is_ascii_hexdigit).count(),
+                _ => x.bytes().take_while(u8::is_ascii_digit).count(),
+            };
+
+            if len == 0 {
+                out.push('&');
+                continue;
+            }
+
+            let ch = u32::from_str_radix(&x[..len], radix)
+                .ok()
+                .and_then(char::from_u32)
+                .unwrap_or(char::REPLACEMENT_CHARACTER);
+
+            out.push(ch);
+
+            // Numeric references are decoded even without the `;`.
+            rest = x[len..].strip_prefix(';').unwrap_or(&x[len..]);
+            continue;
+        }
+
+        let len = rest.bytes().take_while(u8::is_ascii_alphanumeric).count();
+
+        if len == 0 || !rest[len..].starts_with(';') {
+            out.push('&');
+            continue;
+        }
+
+        let ch = match &rest[..len] {
+            "amp" => '&',
+            "apos" => '\'',
+            "colon" => ':',
+            "gt" => '>',
+            "lt" => '<',
+            "nbsp" => '\u{a0}',
+            "NewLine" => '\n',
+            "quot" => '"',
+            "Tab" => '\t',
+
+            _ => return None,
+        };
+
+        out.push(ch);
+        rest = &rest[len + 1..];
+    }
+
+    out.push_str(rest);
+    Some(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn render(input: &str) -> String {
+        let mut s = String::new();
+        Sanitize::new(input, &SanitizePolicy::LINKS).write(&mut s);
+        s
+    }
+
+    #[test]
+    fn removes_non_ascii_attribute_names() {
+        assert_eq!(render("<b é=1>x</b>"), "<b>x</b>");
+        assert_eq!(render("<b éé title=x>x</b>"), "<b>x</b>");
+        assert_eq!(
+            render(r#"<a 链接="x" href="/a">x</a>"#),
+            r#"<a href="/a">x</a>"#
+        );
+    }
+
+    #[test]
+    fn removes_non_ascii_tag_names() {
+        assert_eq!(render("<bé>x</bé>"), "x");
+        assert_eq!(render("<b>x</bé></b>"), "<b>x</b>");
+        assert_eq!(render("<é>x"), "&lt;é&gt;x");
+    }
+
+    #[test]
+    fn keeps_non_ascii_attribute_values() {
+        assert_eq!(
+            render(r#"<a href="/café?q=ü" title="é">é</a>"#),
+            r#"<a href="/café?q=ü" title="é">é</a>"#
+        );
+        assert_eq!(render("<a href=/é>x</a>"), r#"<a href="/é">x</a>"#);
+    }
+
+    #[test]
+    fn closes_open_elements() {
+        assert_eq!(render("<p><b>x"), "<p><b>x</b></p>");
+    }
+
+    #[test]
+    fn keeps_bare_ampersands_in_urls() {
+        assert_eq!(
+            render(r#"<a href="https://example.com/?a=1&b=2">x</a>"#),
+            r#"<a href="https://example.com/?a=1&amp;b=2">x</a>"#
+        );
+        assert_eq!(
+            render(r#"<a href="/?a=1&#;b=2&c">x</a>"#),
+            r#"<a href="/?a=1&amp;#;b=2&amp;c">x</a>"#
+        );
+    }
+
+    #[test]
+    fn rejects_encoded_schemes() {
+        assert_eq!(
+            render(r#"<a href="javascript&colon;alert(1)">x</a>"#),
+            "<a>x</a>"
+        );
+        assert_eq!(
+            render(r#"<a href="&#106avascript:alert(1)">x</a>"#),
+            "<a>x</a>"
+        );
+        assert_eq!(
+            render(r#"<a href="java&Tab;script:alert(1)">x</a>"#),
+            "<a>x</a>"
+        );
+    }
+}
@@ -0,0 +1,155 @@
+use crate::{Buffer, EscapeBuffer, EscapeContext, Html, HtmlRef};
+
+/// A list of CSS declarations for a `style` attribute.
+///
+/// Declarations are added with [`Style::with`], which takes a property name
+/// and any value that implements [`Html`]. Values are escaped as CSS values,
+/// and `;` is escaped as well, so that a value cannot add declarations of its
+/// own. A declaration whose value renders nothing, such as [`None`], is left
+/// out, and so is one whose property name is not made of ASCII letters, digits,
+/// `-` and `_`. An empty list is treated like [`None`], so
+/// [`Attr`](crate::Attr) and templates leave out a `style` attribute whose
+/// value is empty.
+///
+/// In [`asx!`](crate::asx), `style: { property: value, ... }` builds a `Style`
+/// for the element.
+///
+/// # Example
+///
+/// ```rust
+/// use avosetta::{asx, Html, Style};
+///
+/// let width = 40;
+/// let color: Option<&str> = None;
+///
+/// let style = Style::new()
+///     .with("width", format_args!("{width}%"))
+///     .with("color", color)
+///     .with("font-family", "x; position: fixed");
+///
+/// let page = asx! {
+///     div[style=style] { }
+/// };
+///
+/// let mut output = String::new();
+/// page.write(&mut output);
+///
+/// assert_eq!(
+///     output,
+///     r#"<div style="width:40%;font-family:x\3B  position: fixed"></div>"#,
+/// );
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct Style {
+    css: String,
+}
+
+impl Style {
+    /// Creates an empty list of declarations.
+    #[inline]
+    pub const fn new() -> Self {
+        Self { css: String::new() }
+    }
+
+    /// Adds the declaration `property: value`.
+    #[inline]
+    pub fn with<V>(mut self, property: &str, value: V) -> Self
+    where
+        V: for<'a> Html<EscapeBuffer<'a, String>>,
+    {
+        self.push(property, value);
+        self
+    }
+
+    /// Adds the declaration `property: value`, unless `property` is not a valid
+    /// property name or `value` renders nothing.
+    pub fn push<V>(&mut self, property: &str, value: V)
+    where
+        V: for<'a> Html<EscapeBuffer<'a, String>>,
+    {
+        let valid = !property.is_empty()
+            && property
+                .bytes()
+                .all(|x| x.is_ascii_alphanumeric() || matches!(x, b'-' | b'_'));
+
+        if !valid || value.is_none() || value.is_false() {
+            return;
+        }
+
+        let start = self.css.len();
+
+        if !self.css.is_empty() {
+            self.css.push(';');
+        }
+
+        self.css.push_str(property);
+        self.css.push(':');
+
+        let value_start = self.css.len();
+        value.write(&mut EscapeBuffer::new(&mut self.css, EscapeContext::Css));
+
+        if self.css.len() == value_start {
+            self.css.truncate(start);
+        } else if self.css[value_start..].contains(';') {
+            let value = self.css.split_off(value_start);
+            self.css.push_str(&value.replace(';', "\\3B "));
+        }
+    }
+
+    /// Adds declarations written by a template at compile time, which are
+    /// trusted and already escaped for HTML.
+    #[doc(hidden)]
+    #[inline]
+    pub fn __trusted(mut self, css: &str) -> Self {
+        if !self.css.is_empty() {
+            self.css.push(';');
+        }
+
+        self.css.push_str(css);
+        self
+    }
+
+    /// Returns `true` if the list holds no declarations.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.css.is_empty()
+    }
+
+    /// Returns the declarations as they are written.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        &self.css
+    }
+}
+
+impl<B> Html<B> for Style
+where
+    B: Buffer + ?Sized,
+{
+    #[inline]
+    fn write(self, s: &mut B) {
+        self.write_ref(s);
+    }
+
+    #[inline]
+    fn is_none(&self) -> bool {
+        self.is_empty()
+    }
+}
+
+impl<B> HtmlRef<B> for Style
+where
+    B: Buffer + ?Sized,
+{
+    // The declarations are already escaped, and CSS escaping leaves no
+    // characters that are special in HTML text or attributes.
+    #[inline]
+    fn write_ref(&self, s: &mut B) {
+        s.push_str(&self.css);
+    }
+
+    #[inline]
+    fn is_none(&self) -> bool {
+        self.is_empty()
+    }
+}
@@ -0,0 +1,477 @@
+use std::{
+    future::{Future, poll_fn},
+    pin::Pin,
+    sync::{
+        Mutex, MutexGuard, TryLockError,
+        atomic::{AtomicUsize, Ordering},
+    },
+    task::{Context, Poll},
+};
+
+use crate::{Buffer, Html};
+
+type PendingFuture<'a> = Pin<Box<dyn Future<Output = String> + Send + 'a>>;
+
+type Pending<'a> = Vec<(usize, PendingFuture<'a>)>;
+
+/// Tracks deferred sections of a page for out-of-order streaming.
+///
+/// A deferred section is written as a placeholder holding its fallback content,
+/// and the section itself is rendered once its future completes. Send the page
+/// shell first, then call [`Suspense::next`] repeatedly to receive each
+/// finished section, in the order they complete, as a chunk that can be
+/// appended to the response. Each chunk holds the rendered section in a
+/// `<template>` and a small inline script that swaps it into place.
+///
+/// Sections are usually deferred with the `@defer` template syntax, which takes
+/// a `&Suspense`, the deferred body, and an optional `else` fallback. The body
+/// may contain `@await` nodes.
+///
+/// Placeholders and chunks are matched by element ids starting with
+/// `avosetta`. Give each `Suspense` streamed into the same page its own prefix
+/// with [`Suspense::with_prefix`], so that their ids do not collide. Pages
+/// served with a Content Security Policy can pass the policy's nonce to
+/// [`Suspense::with_nonce`] to allow the inline swap scripts.
+///
+/// # Example
+///
+/// ```rust
+/// use avosetta::{asx, Html, Suspense};
+///
+/// async fn load_comments() -> Vec<&'static str> {
+///     vec!["First!"]
+/// }
+///
+/// let suspense = &Suspense::new();
+/// let page = asx! {
+///     main {
+///         h1 { "Post" }
+///
+///         @defer suspense {
+///             ul {
+///                 @for comment in load_comments().await {
+///                     li { @comment }
+///                 }
+///             }
+///         } else {
+///             p { "Loading comments" }
+///         }
+///     }
+/// };
+///
+/// let mut shell = String::new();
+/// page.write(&mut shell);
+///
+/// assert_eq!(
+///     shell,
+///     r#"<main><h1>Post</h1><avosetta-suspense id="avosetta-p0"><p>Loading comments</p></avosetta-suspense></main>"#,
+/// );
+///
+/// # let future = async {
+/// while let Some(chunk) = suspense.next().await {
+///     assert!(chunk.starts_with(r#"<template id="avosetta-t0"><ul><li>First!</li></ul></template><script>"#));
+/// }
+/// # };
+/// # let mut future = std::pin::pin!(future);
+/// # let mut cx = std::task::Context::from_waker(std::task::Waker::noop());
+/// # assert!(future.as_mut().poll(&mut cx).is_ready());
+/// ```
+pub struct Suspense<'a> {
+    prefix: String,
+    nonce: Option<String>,
+    next_id: AtomicUsize,
+    pending: Mutex<Pending<'a>>,
+
+    /// Sections deferred since the last poll. They are kept apart from
+    /// `pending`, which is locked while sections are polled, so that a section
+    /// can defer another one.
+    deferred: Mutex<Pending<'a>>,
+}
+
+impl<'a> Suspense<'a> {
+    /// Creates an empty `Suspense` with no pending sections.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the prefix of the element ids used to match placeholders with
+    /// their chunks, which is `avosetta` by default.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `prefix` is empty or contains a character other than an ASCII
+    /// letter, digit, `-`, or `_`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use avosetta::{Html, Suspense};
+    ///
+    /// let suspense = Suspense::new().with_prefix("sidebar");
+    /// let placeholder = suspense.defer(async { "Done" }, "Loading");
+    ///
+    /// let mut html = String::new();
+    /// placeholder.write(&mut html);
+    ///
+    /// assert_eq!(html, r#"<avosetta-suspense id="sidebar-p0">Loading</avosetta-suspense>"#);
+    /// ```
+    pub fn with_prefix(mut self, prefix: impl Into<String>) -> Self {
+        let prefix = prefix.into();
+
+        assert!(
+            !prefix.is_empty()
+                && prefix
+                    .bytes()
+                    .all(|x| x.is_ascii_alphanumeric() || x == b'-' || x == b'_'),
+            "invalid suspense id prefix `{prefix}`",
+        );
+
+        self.prefix = prefix;
+        self
+    }
+
+    /// Sets the Content Security Policy nonce written on each chunk's inline
+    /// script.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use avosetta::Suspense;
+    ///
+    /// let suspense = Suspense::new().with_nonce("r4nd0m");
+    /// let _ = suspense.defer(async { "Done" }, ());
+    ///
+    /// # let future = async {
+    /// let chunk = suspense.next().await.unwrap();
+    /// assert!(chunk.contains(r#"<script nonce="r4nd0m">"#));
+    /// # };
+    /// # let mut future = std::pin::pin!(future);
+    /// # let mut cx = std::task::Context::from_waker(std::task::Waker::noop());
+    /// # assert!(future.as_mut().poll(&mut cx).is_ready());
+    /// ```
+    pub fn with_nonce(mut self, nonce: impl Into<String>) -> Self {
+        self.nonce = Some(nonce.into());
+        self
+    }
+
+    /// Defers rendering the output of `future`, returning the placeholder that
+    /// should be written in its place.
+    ///
+    /// The placeholder renders `fallback` until the deferred section is swapped
+    /// in. Use `()` for an empty fallback.
+    pub fn defer<F, T>(&self, future: F, fallback: T) -> Placeholder<'_, T>
+    where
+        F: Future + Send + 'a,
+        F::Output: Html,
+    {
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+
+        let future = Box::pin(async move {
+            let mut s = String::new();
+            future.await.write(&mut s);
+            s
+        });
+
+        lock(&self.deferred).push((id, future));
+
+        Placeholder {
+            prefix: &self.prefix,
+            id,
+            fallback,
+        }
+    }
+
+    /// Returns `true` if no deferred sections are waiting to be rendered.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        let pending = match self.pending.try_lock() {
+            Ok(x) => x.is_empty(),
+            Err(TryLockError::Poisoned(x)) => x.into_inner().is_empty(),
+
+            // The sections are being polled, so at least one is pending.
+            Err(TryLockError::WouldBlock) => false,
+        };
+
+        pending && lock(&self.deferred).is_empty()
+    }
+
+    /// Waits for the next deferred section to complete and returns its chunk,
+    /// or `None` once every section has been rendered.
+    #[inline]
+    pub fn next(&self) -> impl Future<Output = Option<String>> {
+        poll_fn(|cx| self.poll_next(cx))
+    }
+
+    /// Polls the pending sections, returning the chunk of the first one that
+    /// has completed.
+    ///
+    /// Sections are polled in place. If another call is already polling them,
+    /// this call returns [`Poll::Pending`] and asks to be polled again.
+    pub fn poll_next(&self, cx: &mut Context<'_>) -> Poll<Option<String>> {
+        let mut pending = match self.pending.try_lock() {
+            Ok(x) => x,
+            Err(TryLockError::Poisoned(x)) => x.into_inner(),
+
+            Err(TryLockError::WouldBlock) => {
+                cx.waker().wake_by_ref();
+                return Poll::Pending;
+            }
+        };
+
+        loop {
+            pending.append(&mut lock(&self.deferred));
+
+            if pending.is_empty() {
+                return Poll::Ready(None);
+            }
+
+            let mut i = 0;
+
+            while i < pending.len() {
+                if let Poll::Ready(html) = poll_section(&mut pending, i, cx) {
+                    let (id, _) = pending.swap_remove(i);
+
+                    let mut chunk = String::new();
+                    self.write_chunk(id, &html, &mut chunk);
+                    return Poll::Ready(Some(chunk));
+                }
+
+                i += 1;
+            }
+
+            // Sections deferred while polling have not been polled yet.
+            if lock(&self.deferred).is_empty() {
+                return Poll::Pending;
+            }
+        }
+    }
+
+    fn write_chunk(&self, id: usize, html: &str, s: &mut String) {
+        let mut buf = itoa::Buffer::new();
+        let id = buf.format(id);
+        let prefix = &self.prefix;
+
+        s.push_str("<template id=\"");
+        s.push_str(prefix);
+        s.push_str("-t");
+        s.push_str(id);
+        s.push_str("\">");
+        s.push_str(html);
+        s.push_str("</template><script");
+
+        if let Some(nonce) = &self.nonce {
+            s.push_str(" nonce=\"");
+            s.push_text(nonce);
+            s.push('"');
+        }
+
+        s.push_str(">(()=>{const t=document.getElementById(\"");
+        s.push_str(prefix);
+        s.push_str("-t");
+        s.push_str(id);
+        s.push_str("\");document.getElementById(\"");
+        s.push_str(prefix);
+        s.push_str("-p");
+        s.push_str(id);
+        s.push_str(
+            "\").replaceWith(t.content);t.remove();document.currentScript.remove()})()</script>",
+        );
+    }
+}
+
+#[inline]
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(|x| x.into_inner())
+}
+
+/// Polls the section at index `i`, removing it if it panics so that the other
+/// sections can still be polled.
+fn poll_section(pending: &mut Pending<'_>, i: usize, cx: &mut Context<'_>) -> Poll<String> {
+    struct Guard<'v, 'a>(&'v mut Pending<'a>, usize);
+
+    impl Drop for Guard<'_, '_> {
+        fn drop(&mut self) {
+            if std::thread::panicking() {
+                drop(self.0.swap_remove(self.1));
+            }
+        }
+    }
+
+    let guard = Guard(pending, i);
+    guard.0[guard.1].1.as_mut().poll(cx)
+}
+
+impl Default for Suspense<'_> {
+    fn default() -> Self {
+        Self {
+            prefix: String::from("avosetta"),
+            nonce: None,
+            next_id: AtomicUsize::new(0),
+            pending: Mutex::default(),
+            deferred: Mutex::default(),
+        }
+    }
+}
+
+impl std::fmt::Debug for Suspense<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Suspense")
+            .field("prefix", &self.prefix)
+            .field("nonce", &self.nonce)
+            .field("next_id", &self.next_id)
+            .field(
+                "pending",
+                &(lock(&self.deferred).len() + lock(&self.pending).len()),
+            )
+            .finish()
+    }
+}
+
+/// The placeholder written in place of a deferred section.
+///
+/// Returned by [`Suspense::defer`]. Renders its fallback content inside an
+/// `avosetta-suspense` element that the section's chunk later replaces.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Placeholder<'a, T> {
+    prefix: &'a str,
+    id: usize,
+    fallback: T,
+}
+
+impl<T> Placeholder<'_, T> {
+    /// Returns the id of the deferred section this placeholder stands for.
+    #[inline]
+    pub const fn id(&self) -> usize {
+        self.id
+    }
+}
+
+impl<T, B> Html<B> for Placeholder<'_, T>
+where
+    T: Html<B>,
+    B: Buffer + ?Sized,
+{
+    fn write(self, s: &mut B) {
+        s.push_str("<avosetta-suspense id=\"");
+        s.push_str(self.prefix);
+        s.push_str("-p");
+        self.id.write(s);
+        s.push_str("\">");
+        self.fallback.write(s);
+        s.push_str("</avosetta-suspense>");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{panic::AssertUnwindSafe, sync::Arc, task::Waker};
+
+    use super::*;
+
+    /// A future that is pending for `polls` polls before it completes.
+    struct After {
+        polls: usize,
+        html: &'static str,
+    }
+
+    impl Future for After {
+        type Output = &'static str;
+
+        fn poll(mut self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Self::Output> {
+            match self.polls {
+                0 => Poll::Ready(self.html),
+
+                _ => {
+                    self.polls -= 1;
+                    Poll::Pending
+                }
+            }
+        }
+    }
+
+    fn after(polls: usize, html: &'static str) -> After {
+        After { polls, html }
+    }
+
+    fn poll(suspense: &Suspense) -> Poll<Option<String>> {
+        suspense.poll_next(&mut Context::from_waker(Waker::noop()))
+    }
+
+    /// Returns the rendered section in a chunk.
+    fn section(chunk: &str) -> &str {
+        let start = chunk.find('>').unwrap() + 1;
+        &chunk[start..chunk.find("</template>").unwrap()]
+    }
+
+    #[test]
+    fn polls_every_pending_section() {
+        let suspense = Suspense::new();
+        let _ = suspense.defer(after(4, "a"), ());
+        let _ = suspense.defer(after(0, "b"), ());
+        let _ = suspense.defer(after(1, "c"), ());
+
+        let mut sections = Vec::new();
+
+        while !suspense.is_empty() {
+            if let Poll::Ready(Some(chunk)) = poll(&suspense) {
+                sections.push(section(&chunk).to_owned());
+            }
+        }
+
+        assert_eq!(sections, ["b", "c", "a"]);
+        assert_eq!(poll(&suspense), Poll::Ready(None));
+    }
+
+    #[test]
+    fn keeps_sections_while_another_poll_is_running() {
+        let suspense = Suspense::new();
+        let _ = suspense.defer(after(0, "a"), ());
+
+        let guard = suspense.pending.lock().unwrap();
+        assert_eq!(poll(&suspense), Poll::Pending);
+        assert!(!suspense.is_empty());
+        drop(guard);
+
+        let chunk = poll(&suspense);
+        assert!(matches!(&chunk, Poll::Ready(Some(x)) if section(x) == "a"));
+    }
+
+    #[test]
+    fn polls_sections_deferred_by_a_section() {
+        let suspense = Arc::new(Suspense::new());
+        let inner = Arc::clone(&suspense);
+
+        let _ = suspense.defer(
+            async move {
+                let _ = inner.defer(after(0, "inner"), ());
+                "outer"
+            },
+            (),
+        );
+
+        let mut sections = Vec::new();
+
+        while let Poll::Ready(Some(chunk)) = poll(&suspense) {
+            sections.push(section(&chunk).to_owned());
+        }
+
+        assert_eq!(sections, ["outer", "inner"]);
+    }
+
+    #[test]
+    fn drops_a_section_that_panics() {
+        let suspense = Suspense::new();
+        let _ = suspense.defer(async { panic!("section failed") as &str }, ());
+        let _ = suspense.defer(after(1, "b"), ());
+
+        let result = std::panic::catch_unwind(AssertUnwindSafe(|| poll(&suspense)));
+        assert!(result.is_err());
+
+        assert_eq!(poll(&suspense).map(|x| x.is_some()), Poll::Pending);
+
+        let chunk = poll(&suspense);
+        assert!(matches!(&chunk, Poll::Ready(Some(x)) if section(x) == "b"));
+        assert_eq!(poll(&suspense), Poll::Ready(None));
+    }
+}
@@ -0,0 +1,166 @@
+use crate::{Buffer, Html, HtmlRef};
+
+/// A URL whose scheme is checked against an allow-list before it is rendered.
+///
+/// Escaping stops a URL from breaking out of an attribute, but a well-formed
+/// `javascript:` or `data:` URL is still dangerous in `href` or `src`. `Url`
+/// renders its value only if the URL is relative or its scheme is allowed, and
+/// renders [`Url::BLOCKED`] otherwise, so the link stays inert. The scheme is
+/// found the way browsers find it: ASCII tabs and newlines are ignored, leading
+/// spaces and control characters are skipped, and letters are compared
+/// case-insensitively.
+///
+/// [`Url::new`] allows the schemes in [`Url::DEFAULT_SCHEMES`]. Use
+/// [`Url::with_schemes`] to allow a different set, or [`Url::is_allowed`] to
+/// check the value when it is constructed.
+///
+/// # Example
+///
+/// ```rust
+/// use avosetta::{asx, Html, Url};
+///
+/// let page = asx! {
+///     a[href=Url::new("/about")] { "About" }
+///     a[href=Url::new("JavaScript:alert(1)")] { "Evil" }
+/// };
+///
+/// let mut output = String::new();
+/// page.write(&mut output);
+///
+/// assert_eq!(
+///     output,
+///     r#"<a href="/about">About</a><a href="about:invalid">Evil</a>"#,
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Url<T> {
+    value: T,
+    schemes: &'static [&'static str],
+}
+
+impl Url<()> {
+    /// The schemes allowed by [`Url::new`].
+    pub const DEFAULT_SCHEMES: &'static [&'static str] = &["http", "https", "mailto", "tel"];
+
+    /// The URL rendered in place of a URL whose scheme is not allowed.
+    pub const BLOCKED: &'static str = "about:invalid";
+}
+
+impl<T> Url<T>
+where
+    T: AsRef<str>,
+{
+    /// Creates a URL that allows the schemes in [`Url::DEFAULT_SCHEMES`], as
+    /// well as relative URLs.
+    #[inline]
+    pub const fn new(value: T) -> Self {
+        Self::with_schemes(value, Url::DEFAULT_SCHEMES)
+    }
+
+    /// Creates a URL that allows the lowercase schemes in `schemes`, as well as
+    /// relative URLs.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use avosetta::Url;
+    ///
+    /// let url = Url::with_schemes("ftp://example.com", &["ftp"]);
+    /// assert!(url.is_allowed());
+    /// assert!(!Url::new("ftp://example.com").is_allowed());
+    /// ```
+    #[inline]
+    pub const fn with_schemes(value: T, schemes: &'static [&'static str]) -> Self {
+        Self { value, schemes }
+    }
+
+    /// Returns the URL, whether or not its scheme is allowed.
+    #[inline]
+    pub fn get(&self) -> &str {
+        self.value.as_ref()
+    }
+
+    /// Consumes the `Url`, returning the wrapped value.
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+
+    /// Returns `true` if the URL is relative or its scheme is allowed.
+    pub fn is_allowed(&self) -> bool {
+        let mut scheme = [0; 32];
+
+        match parse_scheme(self.get(), &mut scheme) {
+            Scheme::Relative => true,
+            Scheme::Absolute(x) => self.schemes.iter().any(|y| y.as_bytes() == x),
+            Scheme::Invalid => false,
+        }
+    }
+
+    /// Returns the URL if it is allowed, or [`Url::BLOCKED`] otherwise.
+    #[inline]
+    pub fn as_allowed(&self) -> &str {
+        if self.is_allowed() {
+            self.get()
+        } else {
+            Url::BLOCKED
+        }
+    }
+}
+
+enum Scheme<'a> {
+    Relative,
+    Absolute(&'a [u8]),
+    Invalid,
+}
+
+/// Finds the scheme of `url`, writing it in lowercase to `buf`.
+fn parse_scheme<'a>(url: &str, buf: &'a mut [u8; 32]) -> Scheme<'a> {
+    let bytes = url
+        .bytes()
+        .skip_while(|x| *x <= b' ')
+        .filter(|x| !matches!(x, b'\t' | b'\n' | b'\r'));
+
+    for (len, byte) in bytes.enumerate() {
+        match byte {
+            b':' if len > 0 => return Scheme::Absolute(&buf[..len]),
+
+            b'a'..=b'z' | b'A'..=b'Z' => {}
+            b'0'..=b'9' | b'+' | b'-' | b'.' if len > 0 => {}
+
+            // Anything else before a `:` means the URL has no scheme.
+            _ => return Scheme::Relative,
+        }
+
+        // Schemes longer than the buffer are never on an allow-list.
+        let Some(x) = buf.get_mut(len) else {
+            return Scheme::Invalid;
+        };
+
+        *x = byte.to_ascii_lowercase();
+    }
+
+    Scheme::Relative
+}
+
+impl<T, B> Html<B> for Url<T>
+where
+    T: AsRef<str>,
+    B: Buffer + ?Sized,
+{
+    #[inline]
+    fn write(self, s: &mut B) {
+        s.push_text(self.as_allowed());
+    }
+}
+
+impl<T, B> HtmlRef<B> for Url<T>
+where
+    T: AsRef<str>,
+    B: Buffer + ?Sized,
+{
+    #[inline]
+    fn write_ref(&self, s: &mut B) {
+        s.push_text(self.as_allowed());
+    }
+}